@@ -0,0 +1,134 @@
+use std::{
+    error::Error,
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::solution::DynSolution;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timings {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+        Timings {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Timings of each stage of one day. A part that was not benchmarked is `None`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DayTimings {
+    pub parse: Timings,
+    pub part1: Option<Timings>,
+    pub part2: Option<Timings>,
+}
+
+fn time<T, F: FnMut() -> T>(runs: usize, mut f: F) -> Timings {
+    Timings::from_samples(
+        (0..runs)
+            .map(|_| {
+                let start = Instant::now();
+                let result = f();
+                let elapsed = start.elapsed();
+                drop(black_box(result));
+                elapsed
+            })
+            .collect(),
+    )
+}
+
+/// Runs each stage of `solution` on `input` `runs` times. Both parts are timed on the same parsed
+/// input; `part` restricts timing to one of them. Fails without timing anything if the input does
+/// not parse or a selected part has no answer.
+pub fn bench(
+    solution: &dyn DynSolution,
+    input: &str,
+    runs: usize,
+    part: Option<u8>,
+) -> Result<DayTimings, Box<dyn Error>> {
+    let parsed = solution.parse(input)?;
+    let parsed = &*parsed;
+    if part != Some(2) {
+        solution.part1(parsed)?;
+    }
+    if part != Some(1) {
+        solution.part2(parsed)?;
+    }
+    Ok(DayTimings {
+        parse: time(runs, || solution.parse(black_box(input))),
+        part1: (part != Some(2)).then(|| time(runs, || solution.part1(black_box(parsed)))),
+        part2: (part != Some(1)).then(|| time(runs, || solution.part2(black_box(parsed)))),
+    })
+}
+
+/// Renders the results as a table with one row per stage.
+pub fn table(results: &[(usize, DayTimings)]) -> String {
+    let mut out = format!(
+        "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}\n",
+        "day", "stage", "min", "median", "max"
+    );
+    for (day, timings) in results {
+        for (stage, t) in stages(timings) {
+            writeln!(
+                out,
+                "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+                day,
+                stage,
+                format!("{:.2?}", t.min),
+                format!("{:.2?}", t.median),
+                format!("{:.2?}", t.max)
+            )
+            .unwrap();
+        }
+    }
+    out
+}
+
+/// Renders the results as JSON, with times in nanoseconds.
+pub fn json(runs: usize, results: &[(usize, DayTimings)]) -> String {
+    let days = results
+        .iter()
+        .map(|(day, timings)| {
+            let mut out = format!("{{\"day\": {}", day);
+            for (stage, t) in stages(timings) {
+                write!(
+                    out,
+                    ", \"{}\": {{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                    stage,
+                    t.min.as_nanos(),
+                    t.median.as_nanos(),
+                    t.max.as_nanos()
+                )
+                .unwrap();
+            }
+            out.push('}');
+            out
+        })
+        .collect::<Vec<_>>();
+    format!(
+        "{{\"runs\": {}, \"days\": [\n  {}\n]}}\n",
+        runs,
+        days.join(",\n  ")
+    )
+}
+
+fn stages(timings: &DayTimings) -> impl Iterator<Item = (&'static str, &Timings)> {
+    [
+        ("parse", Some(&timings.parse)),
+        ("part1", timings.part1.as_ref()),
+        ("part2", timings.part2.as_ref()),
+    ]
+    .into_iter()
+    .filter_map(|(stage, t)| Some((stage, t?)))
+}
@@ -0,0 +1,360 @@
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// An arbitrary-precision signed integer. Division truncates towards zero and the remainder has
+/// the sign of the dividend, as with the primitive integer types, so it can be used with the
+/// generic functions in `numbers`.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    /// Little-endian base 2^32 digits, without trailing zeros.
+    magnitude: Vec<u32>,
+}
+
+fn trim(mut digits: Vec<u32>) -> Vec<u32> {
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    digits
+}
+
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let sum = x as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        result.push(sum as u32);
+        carry = sum >> 32;
+    }
+    result.push(carry as u32);
+    trim(result)
+}
+
+/// `a - b` where `a >= b`.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let diff = x as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        result.push(diff as u32);
+        borrow = (diff < 0) as i64;
+    }
+    assert_eq!(borrow, 0);
+    trim(result)
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let product = x as u64 * y as u64 + result[i + j] as u64 + carry;
+            result[i + j] = product as u32;
+            carry = product >> 32;
+        }
+        result[i + b.len()] = carry as u32;
+    }
+    trim(result)
+}
+
+/// Divides by a single digit, returning the quotient and remainder.
+fn div_rem_digit(a: &[u32], b: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0; a.len()];
+    let mut rem = 0u64;
+    for (i, &x) in a.iter().enumerate().rev() {
+        let cur = rem << 32 | x as u64;
+        quotient[i] = (cur / b as u64) as u32;
+        rem = cur % b as u64;
+    }
+    (trim(quotient), rem as u32)
+}
+
+/// Binary long division. Panics if `b` is zero.
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!b.is_empty(), "attempt to divide by zero");
+    if cmp_magnitude(a, b) == Ordering::Less {
+        return (Vec::new(), a.to_vec());
+    }
+    if let [b] = b {
+        let (quotient, rem) = div_rem_digit(a, *b);
+        return (quotient, trim(vec![rem]));
+    }
+    let mut quotient = vec![0; a.len()];
+    let mut rem = Vec::new();
+    for bit in (0..a.len() * 32).rev() {
+        rem = add_magnitude(&rem, &rem);
+        if a[bit / 32] >> (bit % 32) & 1 == 1 {
+            rem = add_magnitude(&rem, &[1]);
+        }
+        if cmp_magnitude(&rem, b) != Ordering::Less {
+            rem = sub_magnitude(&rem, b);
+            quotient[bit / 32] |= 1 << (bit % 32);
+        }
+    }
+    (trim(quotient), rem)
+}
+
+impl BigInt {
+    fn from_parts(negative: bool, magnitude: Vec<u32>) -> Self {
+        let magnitude = trim(magnitude);
+        BigInt {
+            negative: negative && !magnitude.is_empty(),
+            magnitude,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::from_parts(false, self.magnitude.clone())
+    }
+
+    /// -1, 0 or 1 according to the sign.
+    pub fn signum(&self) -> BigInt {
+        match (self.negative, self.is_zero()) {
+            (_, true) => BigInt::default(),
+            (negative, false) => BigInt::from_parts(negative, vec![1]),
+        }
+    }
+
+    fn add_ref(&self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::from_parts(
+                self.negative,
+                add_magnitude(&self.magnitude, &other.magnitude),
+            );
+        }
+        match cmp_magnitude(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::from_parts(
+                other.negative,
+                sub_magnitude(&other.magnitude, &self.magnitude),
+            ),
+            _ => BigInt::from_parts(
+                self.negative,
+                sub_magnitude(&self.magnitude, &other.magnitude),
+            ),
+        }
+    }
+
+    fn sub_ref(&self, other: &BigInt) -> BigInt {
+        self.add_ref(&-other)
+    }
+
+    fn mul_ref(&self, other: &BigInt) -> BigInt {
+        BigInt::from_parts(
+            self.negative != other.negative,
+            mul_magnitude(&self.magnitude, &other.magnitude),
+        )
+    }
+
+    fn div_ref(&self, other: &BigInt) -> BigInt {
+        let (quotient, _) = div_rem_magnitude(&self.magnitude, &other.magnitude);
+        BigInt::from_parts(self.negative != other.negative, quotient)
+    }
+
+    fn rem_ref(&self, other: &BigInt) -> BigInt {
+        let (_, rem) = div_rem_magnitude(&self.magnitude, &other.magnitude);
+        BigInt::from_parts(self.negative, rem)
+    }
+}
+
+impl From<u128> for BigInt {
+    fn from(value: u128) -> Self {
+        BigInt::from_parts(false, (0..4).map(|i| (value >> (32 * i)) as u32).collect())
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        let mut result = BigInt::from(value.unsigned_abs());
+        result.negative = value < 0;
+        result
+    }
+}
+
+impl From<bool> for BigInt {
+    fn from(value: bool) -> Self {
+        BigInt::from(value as u128)
+    }
+}
+
+macro_rules! impl_from {
+    ($wide:ty: $($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(value: $t) -> Self {
+                    BigInt::from(value as $wide)
+                }
+            }
+        )*
+    };
+}
+
+impl_from!(u128: u8, u16, u32, u64, usize);
+impl_from!(i128: i8, i16, i32, i64, isize);
+
+macro_rules! impl_op {
+    ($($op:ident $method:ident $op_assign:ident $method_assign:ident $imp:ident),*) => {
+        $(
+            impl $op<&BigInt> for &BigInt {
+                type Output = BigInt;
+
+                fn $method(self, other: &BigInt) -> BigInt {
+                    self.$imp(other)
+                }
+            }
+
+            impl $op<BigInt> for &BigInt {
+                type Output = BigInt;
+
+                fn $method(self, other: BigInt) -> BigInt {
+                    self.$imp(&other)
+                }
+            }
+
+            impl $op<&BigInt> for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, other: &BigInt) -> BigInt {
+                    self.$imp(other)
+                }
+            }
+
+            impl $op<BigInt> for BigInt {
+                type Output = BigInt;
+
+                fn $method(self, other: BigInt) -> BigInt {
+                    self.$imp(&other)
+                }
+            }
+
+            impl $op_assign<BigInt> for BigInt {
+                fn $method_assign(&mut self, other: BigInt) {
+                    *self = self.$imp(&other);
+                }
+            }
+
+            impl $op_assign<&BigInt> for BigInt {
+                fn $method_assign(&mut self, other: &BigInt) {
+                    *self = self.$imp(other);
+                }
+            }
+        )*
+    };
+}
+
+impl_op!(
+    Add add AddAssign add_assign add_ref,
+    Sub sub SubAssign sub_assign sub_ref,
+    Mul mul MulAssign mul_assign mul_ref,
+    Div div DivAssign div_assign div_ref,
+    Rem rem RemAssign rem_assign rem_ref
+);
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude)
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.magnitude, &other.magnitude),
+            (true, true) => cmp_magnitude(&other.magnitude, &self.magnitude),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const CHUNK: u32 = 1_000_000_000;
+        let mut chunks = Vec::new();
+        let mut magnitude = self.magnitude.clone();
+        while !magnitude.is_empty() {
+            let (quotient, rem) = div_rem_digit(&magnitude, CHUNK);
+            chunks.push(rem);
+            magnitude = quotient;
+        }
+        let mut digits = match chunks.pop() {
+            None => String::from("0"),
+            Some(first) => first.to_string(),
+        };
+        for chunk in chunks.iter().rev() {
+            digits += &format!("{:09}", chunk);
+        }
+        f.pad_integral(!self.negative, "", &digits)
+    }
+}
+
+impl fmt::Debug for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseBigIntError;
+
+impl fmt::Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid integer")
+    }
+}
+
+impl Error for ParseBigIntError {}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+        let mut magnitude = Vec::new();
+        for b in digits.bytes() {
+            magnitude = add_magnitude(&mul_magnitude(&magnitude, &[10]), &[(b - b'0') as u32]);
+        }
+        Ok(BigInt::from_parts(negative, magnitude))
+    }
+}
@@ -0,0 +1,263 @@
+use std::{
+    env,
+    error::Error,
+    fs,
+    io::{stdin, Read},
+    path::PathBuf,
+    process,
+};
+
+use utils::{
+    bench,
+    days::{
+        self,
+        day17::{self, CrucibleRules, Day17},
+    },
+    solution::{DynSolution, Solution, SolveError},
+};
+
+const USAGE: &str = "\
+usage: aoc run <day> [--input PATH] [--part 1|2]
+       aoc run all [--input DIR] [--part 1|2]
+       aoc run 17 [--input PATH] [--part 1|2] [--min-straight N] [--max-straight N]
+                  [--stop-early yes|no] [--render]
+       aoc bench <day>|all [--input PATH|DIR] [--part 1|2] [--runs N] [--json PATH]
+
+A single day reads its input from PATH, or from stdin if no --input is given.
+`all` reads <DIR>/<day>.txt for every day (DIR defaults to `inputs`) and skips
+days whose input is missing.
+
+For day 17, the crucible flags change the movement rules of the selected part
+(part 1 by default) and print its least heat loss, and `--render` draws the best
+path over the map.
+
+`bench` times parse, part1 and part2 over N runs (default 10), prints min,
+median and max of each, and writes the same numbers to a JSON file (default
+`bench.json`).";
+
+#[derive(PartialEq, Eq)]
+enum Command {
+    Run,
+    Bench,
+}
+
+enum Target {
+    Day(usize),
+    All,
+}
+
+struct Options {
+    command: Command,
+    target: Target,
+    input: Option<PathBuf>,
+    part: Option<u8>,
+    runs: usize,
+    json: PathBuf,
+    min_straight: Option<u8>,
+    max_straight: Option<u8>,
+    can_stop_early: Option<bool>,
+    render: bool,
+}
+
+impl Options {
+    /// Crucible rules for day 17 if any of the crucible flags were given.
+    fn crucible_rules(&self) -> Option<CrucibleRules> {
+        if self.min_straight.is_none()
+            && self.max_straight.is_none()
+            && self.can_stop_early.is_none()
+            && !self.render
+        {
+            return None;
+        }
+        let base = match self.part {
+            Some(2) => CrucibleRules::ULTRA_CRUCIBLE,
+            _ => CrucibleRules::CRUCIBLE,
+        };
+        Some(CrucibleRules {
+            min_straight: self.min_straight.unwrap_or(base.min_straight),
+            max_straight: self.max_straight.unwrap_or(base.max_straight),
+            can_stop_early: self.can_stop_early.unwrap_or(base.can_stop_early),
+        })
+    }
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Options, String> {
+    let command = match args.next().as_deref() {
+        Some("run") => Command::Run,
+        Some("bench") => Command::Bench,
+        Some(cmd) => return Err(format!("unknown command: {}", cmd)),
+        None => return Err(String::from("no command given")),
+    };
+    let target = match args.next().as_deref() {
+        Some("all") => Target::All,
+        Some(day) => match day.parse() {
+            Ok(day) if days::get(day).is_some() => Target::Day(day),
+            _ => return Err(format!("no such day: {}", day)),
+        },
+        None => return Err(String::from("no day given")),
+    };
+    let mut options = Options {
+        command,
+        target,
+        input: None,
+        part: None,
+        runs: 10,
+        json: PathBuf::from("bench.json"),
+        min_straight: None,
+        max_straight: None,
+        can_stop_early: None,
+        render: false,
+    };
+    let crucible = options.command == Command::Run && matches!(options.target, Target::Day(17));
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} expects a value", arg));
+        match arg.as_str() {
+            "--input" => options.input = Some(PathBuf::from(value()?)),
+            "--part" => match value()?.as_str() {
+                "1" => options.part = Some(1),
+                "2" => options.part = Some(2),
+                part => return Err(format!("no such part: {}", part)),
+            },
+            "--runs" if options.command == Command::Bench => match value()?.parse() {
+                Ok(runs) if runs > 0 => options.runs = runs,
+                _ => return Err(String::from("--runs expects a positive number")),
+            },
+            "--json" if options.command == Command::Bench => options.json = PathBuf::from(value()?),
+            "--min-straight" | "--max-straight" if crucible => {
+                let n = value()?
+                    .parse()
+                    .map_err(|_| format!("{} expects a number", arg))?;
+                match arg.as_str() {
+                    "--min-straight" => options.min_straight = Some(n),
+                    _ => options.max_straight = Some(n),
+                }
+            }
+            "--stop-early" if crucible => match value()?.as_str() {
+                "yes" => options.can_stop_early = Some(true),
+                "no" => options.can_stop_early = Some(false),
+                _ => return Err(String::from("--stop-early expects yes or no")),
+            },
+            "--render" if crucible => options.render = true,
+            _ => return Err(format!("unknown option: {}", arg)),
+        }
+    }
+    Ok(options)
+}
+
+/// Prints the answer to each selected part. A part without an answer does not stop the other
+/// one, but makes the day fail.
+fn run_day(
+    day: Option<usize>,
+    solution: &dyn DynSolution,
+    input: &str,
+    part: Option<u8>,
+) -> Result<(), Box<dyn Error>> {
+    let input = solution.parse(input)?;
+    let mut errors = Vec::new();
+    for n in [1, 2] {
+        if part.is_some_and(|p| p != n) {
+            continue;
+        }
+        let answer = match n {
+            1 => solution.part1(&*input),
+            _ => solution.part2(&*input),
+        };
+        match answer {
+            Ok(answer) if answer.is_empty() => (),
+            Ok(answer) => match day {
+                Some(day) => println!("day {} part {}: {}", day, n, answer),
+                None => println!("{}", answer),
+            },
+            Err(err) => errors.push(format!("part {}: {}", n, err)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("; ").into())
+    }
+}
+
+fn run_crucible(input: &str, rules: CrucibleRules, render: bool) -> Result<(), Box<dyn Error>> {
+    let field = Solution::parse(&Day17, input)?;
+    match day17::best_path(&field, rules) {
+        Some(best) => {
+            println!("{}", best.heat_loss);
+            if render {
+                print!(
+                    "{}",
+                    field.render_overlay(
+                        |&loss| char::from_digit(loss as u32, 10).unwrap(),
+                        best.path,
+                        '#'
+                    )
+                );
+            }
+        }
+        None => return Err(SolveError::new("no path").into()),
+    }
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+    let crucible_rules = options.crucible_rules();
+    let inputs = match options.target {
+        Target::Day(day) => {
+            let input = match options.input {
+                Some(path) => fs::read_to_string(path)?,
+                None => {
+                    let mut input = String::new();
+                    stdin().read_to_string(&mut input)?;
+                    input
+                }
+            };
+            vec![(day, input)]
+        }
+        Target::All => {
+            let dir = options.input.unwrap_or_else(|| PathBuf::from("inputs"));
+            let mut inputs = Vec::new();
+            for day in 1..=days::DAYS {
+                let path = dir.join(format!("{}.txt", day));
+                if !path.exists() {
+                    eprintln!("day {}: no input at {}, skipping", day, path.display());
+                    continue;
+                }
+                inputs.push((day, fs::read_to_string(&path)?));
+            }
+            inputs
+        }
+    };
+    let single = matches!(options.target, Target::Day(_));
+    let mut failed = false;
+    let mut results = Vec::new();
+    for (day, input) in inputs {
+        let solution = days::get(day).unwrap();
+        let result = match options.command {
+            Command::Run => match crucible_rules {
+                Some(rules) => run_crucible(&input, rules, options.render),
+                None => run_day((!single).then_some(day), solution, &input, options.part),
+            },
+            Command::Bench => bench::bench(solution, &input, options.runs, options.part)
+                .map(|timings| results.push((day, timings))),
+        };
+        if let Err(err) = result {
+            eprintln!("day {}: {}", day, err);
+            failed = true;
+        }
+    }
+    if options.command == Command::Bench {
+        print!("{}", bench::table(&results));
+        fs::write(&options.json, bench::json(options.runs, &results))?;
+    }
+    if failed {
+        process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::{
+    parse::ParseError,
+    solution::{Answer, Solution, SolveError},
+};
+
+const NAMES: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn first_and_last<I>(mut iter: I) -> Option<u32>
+where
+    I: Iterator<Item = u32>,
+{
+    let first = iter.next()?;
+    let last = iter.last().unwrap_or(first);
+    Some(first * 10 + last)
+}
+
+/// The calibration values of a line: from its digits alone, if it has any, and from its digits
+/// and spelled out digits.
+pub struct Calibration {
+    digits: Option<u32>,
+    names: u32,
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Calibration>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let digits = first_and_last(line.chars().filter_map(|d| d.to_digit(10)));
+                let names = first_and_last(line.char_indices().filter_map(|(i, d)| {
+                    d.to_digit(10).or_else(|| {
+                        NAMES
+                            .iter()
+                            .position(|name| line[i..].starts_with(name))
+                            .map(|n| n as u32)
+                    })
+                }))
+                .ok_or_else(|| ParseError::at(input, line, "expected a digit"))?;
+                Ok(Calibration { digits, names })
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> impl Answer {
+        input
+            .iter()
+            .map(|line| line.digits)
+            .sum::<Option<u32>>()
+            .ok_or_else(|| SolveError::new("a line has no digit"))
+    }
+
+    fn part2(&self, input: &Self::Input) -> impl Answer {
+        input.iter().map(|line| line.names).sum::<u32>()
+    }
+}
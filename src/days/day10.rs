@@ -0,0 +1,111 @@
+use crate::{
+    direction::Direction4,
+    parse::ParseError,
+    rect::Rect,
+    solution::{Answer, Solution},
+};
+
+fn pipe_dirs(c: char) -> Option<(Direction4, Direction4)> {
+    match c {
+        '|' => Some((Direction4::North, Direction4::South)),
+        '-' => Some((Direction4::East, Direction4::West)),
+        'L' => Some((Direction4::East, Direction4::North)),
+        'J' => Some((Direction4::North, Direction4::West)),
+        '7' => Some((Direction4::West, Direction4::South)),
+        'F' => Some((Direction4::East, Direction4::South)),
+        _ => None,
+    }
+}
+
+/// The pipe field, together with the start tile and a direction in which a pipe leaves it.
+pub struct Maze {
+    field: Rect<char>,
+    start: (isize, isize),
+    dir: Direction4,
+}
+
+fn find_start(field: &Rect<char>) -> Option<((isize, isize), Option<Direction4>)> {
+    let pos = field
+        .cells()
+        .find_map(|(x, y, &c)| (c == 'S').then_some((x as isize, y as isize)))?;
+    let dir = field.neighbors4(pos).find_map(|(d, _, &c)| {
+        pipe_dirs(c)
+            .is_some_and(|(d1, d2)| d1 == d.opposite() || d2 == d.opposite())
+            .then_some(d)
+    });
+    Some((pos, dir))
+}
+
+struct BoundaryIter<'a> {
+    field: &'a Rect<char>,
+    pos: Option<(isize, isize)>,
+    dir: Direction4,
+}
+
+impl<'a> BoundaryIter<'a> {
+    fn new(maze: &'a Maze) -> Self {
+        BoundaryIter {
+            field: &maze.field,
+            pos: Some(maze.start),
+            dir: maze.dir,
+        }
+    }
+}
+
+impl<'a> Iterator for BoundaryIter<'a> {
+    type Item = ((isize, isize), Direction4);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let dir = self.dir;
+        let pos = dir.advance(self.pos?);
+        match *self.field.get(pos)? {
+            'S' => {
+                self.pos = None;
+            }
+            c => {
+                self.pos = Some(pos);
+                let (d1, d2) = pipe_dirs(c)?;
+                self.dir = if d1 == dir.opposite() { d2 } else { d1 };
+            }
+        };
+        Some((pos, dir))
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Maze;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let field = Rect::try_from(input)?;
+        match find_start(&field) {
+            None => Err(ParseError::eof(input, "expected a start tile `S`")),
+            Some((_, None)) => Err(ParseError::at(
+                input,
+                &input[input.find('S').unwrap_or(input.len())..],
+                "no pipe connects to the start tile",
+            )),
+            Some((start, Some(dir))) => Ok(Maze { field, start, dir }),
+        }
+    }
+
+    fn part1(&self, maze: &Self::Input) -> impl Answer {
+        BoundaryIter::new(maze).count() / 2
+    }
+
+    fn part2(&self, maze: &Self::Input) -> impl Answer {
+        let mut len = 0;
+        let mut area = 0;
+        for ((x, y), d) in BoundaryIter::new(maze) {
+            len += 1;
+            area += match d {
+                Direction4::East => -y,
+                Direction4::North => -x,
+                Direction4::West => y,
+                Direction4::South => x,
+            }
+        }
+        (area.abs() - len) / 2 + 1
+    }
+}
@@ -0,0 +1,79 @@
+use std::{convert::identity, mem::swap};
+
+use crate::{
+    parse::ParseError,
+    rect::{Rect, Transposed},
+    solution::{Answer, Solution},
+};
+
+fn solution(field: &Rect<bool>, factor: usize) -> usize {
+    let galaxies: Vec<(usize, usize)> = field
+        .cells()
+        .filter_map(|(x, y, b)| b.then_some((x, y)))
+        .collect();
+    let blank_rows: Vec<usize> = field
+        .iter()
+        .map(|r| !r.iter().copied().any(identity))
+        .enumerate()
+        .filter_map(|(i, b)| b.then_some(i))
+        .collect();
+    let blank_cols: Vec<usize> = Transposed(field)
+        .iter()
+        .map(|r| !r.copied().any(identity))
+        .enumerate()
+        .filter_map(|(i, b)| b.then_some(i))
+        .collect();
+
+    galaxies
+        .iter()
+        .copied()
+        .enumerate()
+        .flat_map(|(i, g1)| galaxies[i + 1..].iter().copied().map(move |g2| (g1, g2)))
+        .map(|((mut x1, mut y1), (mut x2, mut y2))| {
+            if x1 > x2 {
+                swap(&mut x1, &mut x2)
+            }
+            if y1 > y2 {
+                swap(&mut y1, &mut y2)
+            }
+            (x2 - x1)
+                + (factor - 1)
+                    * blank_cols
+                        .iter()
+                        .copied()
+                        .skip_while(|&x| x < x1)
+                        .take_while(|&x| x < x2)
+                        .count()
+                + (y2 - y1)
+                + (factor - 1)
+                    * blank_rows
+                        .iter()
+                        .copied()
+                        .skip_while(|&y| y < y1)
+                        .take_while(|&y| y < y2)
+                        .count()
+        })
+        .sum::<usize>()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Rect<bool>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Rect::parse_grid(input, |c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err("expected `#` or `.`"),
+        })
+    }
+
+    fn part1(&self, field: &Self::Input) -> impl Answer {
+        solution(field, 2)
+    }
+
+    fn part2(&self, field: &Self::Input) -> impl Answer {
+        solution(field, 1000000)
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Tri {
+    Good,
+    Damaged,
+    Unknown,
+}
+
+fn solutions(row: &[Tri], clues: &[usize]) -> u64 {
+    let mut counts: Vec<Vec<u64>> = vec![vec![0; row.len() + 1]; clues.len() + 1];
+    counts[0][0] = 1;
+    for j in 1..=row.len() {
+        counts[0][j] = if row[j - 1] != Tri::Damaged {
+            counts[0][j - 1]
+        } else {
+            0
+        }
+    }
+    for i in 1..=clues.len() {
+        let clue = clues[i - 1];
+        counts[i][0] = 0;
+        for j in 1..=row.len() {
+            counts[i][j] = if row[j - 1] != Tri::Damaged {
+                counts[i][j - 1]
+            } else {
+                0
+            } + if j > clue
+                && row[j - clue - 1] != Tri::Damaged
+                && row[j - clue..j].iter().copied().all(|t| t != Tri::Good)
+            {
+                counts[i - 1][j - clue - 1]
+            } else if j == clue && row[..clue].iter().copied().all(|t| t != Tri::Good) {
+                counts[i - 1][0]
+            } else {
+                0
+            }
+        }
+    }
+    counts[clues.len()][row.len()]
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<(Vec<Tri>, Vec<usize>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let (row, clues) = line
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at(input, line, "expected a row and its clues"))?;
+                Ok((
+                    parse::chars(input, row, |c| match c {
+                        '.' => Some(Tri::Good),
+                        '#' => Some(Tri::Damaged),
+                        '?' => Some(Tri::Unknown),
+                        _ => None,
+                    })?,
+                    clues
+                        .split(',')
+                        .map(|clue| parse::number(input, clue))
+                        .collect::<Result<_, _>>()?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> impl Answer {
+        input
+            .iter()
+            .map(|(row, clues)| solutions(row, clues))
+            .sum::<u64>()
+    }
+
+    fn part2(&self, input: &Self::Input) -> impl Answer {
+        input
+            .iter()
+            .map(|(row, clues)| {
+                solutions(
+                    &[&[Tri::Unknown][..], row].concat().repeat(5)[1..],
+                    &clues.repeat(5),
+                )
+            })
+            .sum::<u64>()
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    parse::ParseError,
+    rect::Rect,
+    solution::{Answer, Solution},
+};
+
+fn vert_reflection(block: &Rect<bool>, k: usize) -> Option<usize> {
+    (1..block.width()).find(|&x| {
+        k == (0..block.height())
+            .flat_map(|y| {
+                (0..Ord::min(x, block.width() - x))
+                    .filter(move |&dx| block[(x + dx, y)] != block[(x - 1 - dx, y)])
+            })
+            .count()
+    })
+}
+
+fn horiz_reflection(block: &Rect<bool>, k: usize) -> Option<usize> {
+    (1..block.height()).find(|&y| {
+        k == (0..Ord::min(y, block.height() - y))
+            .flat_map(|dy| {
+                (0..block.width()).filter(move |&x| block[(x, y + dy)] != block[(x, y - 1 - dy)])
+            })
+            .count()
+    })
+}
+
+fn solution(input: &[Rect<bool>], k: usize) -> usize {
+    input
+        .iter()
+        .map(|block| {
+            vert_reflection(block, k)
+                .or_else(|| horiz_reflection(block, k).map(|n| n * 100))
+                .unwrap()
+        })
+        .sum::<usize>()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Rect<bool>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .trim()
+            .split("\n\n")
+            .map(|block| {
+                Rect::parse_grid_in(input, block, |c| match c {
+                    '#' => Ok(true),
+                    '.' => Ok(false),
+                    _ => Err("expected `#` or `.`"),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> impl Answer {
+        solution(input, 0)
+    }
+
+    fn part2(&self, input: &Self::Input) -> impl Answer {
+        solution(input, 1)
+    }
+}
@@ -0,0 +1,89 @@
+use itertools::iterate;
+
+use crate::{
+    looping_iter::Delooping,
+    parse::ParseError,
+    rect::Rect,
+    solution::{Answer, Solution},
+    state_set::GridCellSet,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    Rolling,
+    Static,
+}
+
+fn roll_north(mut board: Rect<Cell>) -> Rect<Cell> {
+    for y in 0..board.height() {
+        for x in 0..board.width() {
+            if board[(x, y)] == Cell::Rolling {
+                board[(x, y)] = Cell::Empty;
+                let mut new_y = y;
+                while new_y > 0 && board[(x, new_y - 1)] == Cell::Empty {
+                    new_y -= 1;
+                }
+                board[(x, new_y)] = Cell::Rolling;
+            }
+        }
+    }
+    board
+}
+
+fn load(board: &Rect<Cell>) -> usize {
+    board
+        .cells()
+        .filter_map(|(_, y, &c)| (c == Cell::Rolling).then_some(board.height() - y))
+        .sum()
+}
+
+/// Rolls the rocks north, west, south and east, ending in the original orientation.
+fn spin(board: &Rect<Cell>) -> Rect<Cell> {
+    let mut board = board.clone();
+    for _ in 0..4 {
+        board = roll_north(board);
+        board.rotate_cw();
+    }
+    board
+}
+
+/// The positions of the rolling rocks. Only identifies a board among boards with the same
+/// orientation and static rocks, such as the results of `spin`.
+fn rolling_key(board: &Rect<Cell>) -> GridCellSet {
+    let mut key = GridCellSet::for_rect(board);
+    for (x, y, &c) in board.cells() {
+        if c == Cell::Rolling {
+            key.insert((x, y));
+        }
+    }
+    key
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Rect<Cell>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Rect::parse_grid(input, |c| match c {
+            '.' => Ok(Cell::Empty),
+            'O' => Ok(Cell::Rolling),
+            '#' => Ok(Cell::Static),
+            _ => Err("expected one of `.O#`"),
+        })
+    }
+
+    fn part1(&self, input: &Self::Input) -> impl Answer {
+        load(&roll_north(input.clone()))
+    }
+
+    fn part2(&self, input: &Self::Input) -> impl Answer {
+        load(
+            &iterate(input.clone(), spin)
+                .delooping_by_key(rolling_key)
+                .at(1000000000)
+                .unwrap(),
+        )
+    }
+}
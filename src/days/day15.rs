@@ -0,0 +1,84 @@
+use std::str::FromStr;
+
+use crate::{
+    parse::ParseError,
+    solution::{Answer, Solution},
+};
+
+fn hash(value: &str) -> u8 {
+    value
+        .chars()
+        .fold(0, |x, c| x.wrapping_add(c as u8).wrapping_mul(17))
+}
+
+pub enum Step {
+    Insert(String, u32),
+    Delete(String),
+}
+
+impl FromStr for Step {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((key, value)) = s.split_once('=') {
+            Ok(Step::Insert(
+                String::from(key),
+                value.parse().map_err(|_| ())?,
+            ))
+        } else if let Some((key, "")) = s.split_once('-') {
+            Ok(Step::Delete(String::from(key)))
+        } else {
+            Err(())
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<(String, Step)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::eof(input, "expected a line of steps"))?
+            .split(',')
+            .map(|s| {
+                let step = s
+                    .parse()
+                    .map_err(|()| ParseError::at(input, s, format!("invalid step `{}`", s)))?;
+                Ok((String::from(s), step))
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> impl Answer {
+        input.iter().map(|(s, _)| hash(s) as u32).sum::<u32>()
+    }
+
+    fn part2(&self, input: &Self::Input) -> impl Answer {
+        let mut hashmap: [Vec<(&str, u32)>; 256] = [(); 256].map(|_| Vec::new());
+        for (_, step) in input {
+            match step {
+                Step::Delete(key) => hashmap[hash(key) as usize].retain(|(k, _)| k != key),
+                Step::Insert(key, value) => {
+                    let cell = &mut hashmap[hash(key) as usize];
+                    match cell.iter_mut().find(|(k, _)| k == key) {
+                        Some((_, v)) => *v = *value,
+                        None => cell.push((key, *value)),
+                    }
+                }
+            }
+        }
+        hashmap
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, v)| {
+                v.into_iter()
+                    .enumerate()
+                    .map(move |(j, (_, f))| (i + 1) as u32 * (j + 1) as u32 * f)
+            })
+            .sum::<u32>()
+    }
+}
@@ -0,0 +1,261 @@
+use std::{collections::HashMap, mem};
+
+use itertools::{chain, Itertools};
+
+use crate::{
+    direction::Direction4,
+    parse::ParseError,
+    rect::Rect,
+    solution::{Answer, Solution},
+    state_set::GridCellSet,
+};
+
+#[derive(Clone, Copy)]
+pub enum Cell {
+    Empty,
+    SplitterH,
+    SplitterV,
+    MirrorNWSE,
+    MirrorNESW,
+}
+
+type Point = (isize, isize);
+
+const NONE: usize = usize::MAX;
+
+/// Follows a beam entering `pos` in direction `dir` until it leaves the board, hits the flat side
+/// of a splitter, or comes back to where it started. Returns the cells it passes and the position
+/// of the splitter it stops at, if any.
+fn trace(board: &Rect<Cell>, mut pos: Point, mut dir: Direction4) -> (Vec<Point>, Option<Point>) {
+    let start = (pos, dir);
+    let mut cells = Vec::new();
+    while let Some(&cell) = board.get(pos) {
+        cells.push(pos);
+        dir = match (cell, dir) {
+            (Cell::MirrorNESW, _) => dir.reflect_nesw(),
+            (Cell::MirrorNWSE, _) => dir.reflect_nwse(),
+            (Cell::SplitterH, Direction4::North | Direction4::South)
+            | (Cell::SplitterV, Direction4::East | Direction4::West) => return (cells, Some(pos)),
+            _ => dir,
+        };
+        pos = dir.advance(pos);
+        if (pos, dir) == start {
+            break;
+        }
+    }
+    (cells, None)
+}
+
+/// Tarjan's algorithm without recursion. Components are numbered so that every edge leads to a
+/// component with the same or a smaller number.
+fn strongly_connected(succ: &[Vec<usize>]) -> (Vec<usize>, usize) {
+    let n = succ.len();
+    let mut index = vec![NONE; n];
+    let mut low = vec![0; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut component = vec![NONE; n];
+    let mut next_index = 0;
+    let mut count = 0;
+    for root in 0..n {
+        if index[root] != NONE {
+            continue;
+        }
+        index[root] = next_index;
+        low[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        let mut calls = vec![(root, 0)];
+        while let Some((v, i)) = calls.last_mut() {
+            let v = *v;
+            if let Some(&w) = succ[v].get(*i) {
+                *i += 1;
+                if index[w] == NONE {
+                    index[w] = next_index;
+                    low[w] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    calls.push((w, 0));
+                } else if on_stack[w] {
+                    low[v] = low[v].min(index[w]);
+                }
+            } else {
+                calls.pop();
+                if let Some(&(u, _)) = calls.last() {
+                    low[u] = low[u].min(low[v]);
+                }
+                if low[v] == index[v] {
+                    loop {
+                        let w = stack.pop().unwrap();
+                        on_stack[w] = false;
+                        component[w] = count;
+                        if w == v {
+                            break;
+                        }
+                    }
+                    count += 1;
+                }
+            }
+        }
+    }
+    (component, count)
+}
+
+/// The beams leaving all splitters, condensed into strongly connected groups of splitters that
+/// feed each other. Each group knows the cells lit by its own beams; groups lighting many cells
+/// also remember everything lit downstream of them, which is then shared by every entry point
+/// that reaches them.
+struct BeamTracer<'a> {
+    board: &'a Rect<Cell>,
+    splitter_group: HashMap<Point, usize>,
+    own_cells: Vec<Vec<Point>>,
+    downstream: Vec<Vec<usize>>,
+    closure: Vec<Option<GridCellSet>>,
+    seen: Vec<usize>,
+    epoch: usize,
+}
+
+impl<'a> BeamTracer<'a> {
+    fn new(board: &'a Rect<Cell>) -> Self {
+        let splitters = board
+            .cells()
+            .filter_map(|(x, y, &cell)| match cell {
+                Cell::SplitterH => Some(((x as isize, y as isize), Direction4::East)),
+                Cell::SplitterV => Some(((x as isize, y as isize), Direction4::North)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let node: HashMap<Point, usize> = splitters
+            .iter()
+            .enumerate()
+            .map(|(i, &(pos, _))| (pos, i))
+            .collect();
+        let mut succ = vec![Vec::new(); splitters.len()];
+        let mut node_cells = vec![Vec::new(); splitters.len()];
+        for (i, &(pos, dir)) in splitters.iter().enumerate() {
+            for dir in [dir, dir.opposite()] {
+                let (cells, end) = trace(board, dir.advance(pos), dir);
+                node_cells[i].extend(cells);
+                succ[i].extend(end.map(|end| node[&end]));
+            }
+        }
+
+        let (component, count) = strongly_connected(&succ);
+        let mut own_cells = vec![Vec::new(); count];
+        let mut downstream = vec![Vec::new(); count];
+        for (i, cells) in node_cells.into_iter().enumerate() {
+            own_cells[component[i]].extend(cells);
+            downstream[component[i]].extend(
+                succ[i]
+                    .iter()
+                    .map(|&j| component[j])
+                    .filter(|&c| c != component[i]),
+            );
+        }
+        for next in downstream.iter_mut() {
+            next.sort_unstable();
+            next.dedup();
+        }
+
+        let mut tracer = BeamTracer {
+            board,
+            splitter_group: node
+                .into_iter()
+                .map(|(pos, i)| (pos, component[i]))
+                .collect(),
+            own_cells,
+            downstream,
+            closure: vec![None; count],
+            seen: vec![0; count],
+            epoch: 0,
+        };
+        // Groups are numbered downstream first, so every closure can reuse the ones after it. A
+        // closure is a bit per cell, so only groups lighting a cell per 64 of those keep one.
+        let words = (board.width() * board.height()).div_ceil(64);
+        for group in 0..count {
+            if tracer.own_cells[group].len() >= words {
+                let mut cells = GridCellSet::for_rect(board);
+                tracer.collect(group, &mut cells);
+                tracer.closure[group] = Some(cells);
+            }
+        }
+        tracer
+    }
+
+    /// Adds every cell lit by beams leaving `group` and the groups downstream of it.
+    fn collect(&mut self, group: usize, cells: &mut GridCellSet) {
+        self.epoch += 1;
+        let mut stack = vec![group];
+        while let Some(group) = stack.pop() {
+            if mem::replace(&mut self.seen[group], self.epoch) == self.epoch {
+                continue;
+            }
+            if let Some(closure) = &self.closure[group] {
+                cells.union_with(closure);
+                continue;
+            }
+            for &pos in &self.own_cells[group] {
+                cells.insert(pos);
+            }
+            stack.extend(self.downstream[group].iter().copied());
+        }
+    }
+
+    /// The number of cells energized by a beam entering `pos` in direction `dir`.
+    fn energized(&mut self, pos: Point, dir: Direction4) -> usize {
+        let mut cells = GridCellSet::for_rect(self.board);
+        let (path, end) = trace(self.board, pos, dir);
+        for pos in path {
+            cells.insert(pos);
+        }
+        if let Some(end) = end {
+            self.collect(self.splitter_group[&end], &mut cells);
+        }
+        cells.len()
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Rect<Cell>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let board = Rect::parse_grid(input, |c| match c {
+            '.' => Ok(Cell::Empty),
+            '-' => Ok(Cell::SplitterH),
+            '|' => Ok(Cell::SplitterV),
+            '\\' => Ok(Cell::MirrorNWSE),
+            '/' => Ok(Cell::MirrorNESW),
+            _ => Err("expected one of `.-|\\/`"),
+        })?;
+        if board.width() == 0 || board.height() == 0 {
+            return Err(ParseError::eof(input, "expected a non-empty grid"));
+        }
+        Ok(board)
+    }
+
+    fn part1(&self, board: &Self::Input) -> impl Answer {
+        BeamTracer::new(board).energized((0, 0), Direction4::East)
+    }
+
+    fn part2(&self, board: &Self::Input) -> impl Answer {
+        let mut tracer = BeamTracer::new(board);
+        chain(
+            (0..board.width())
+                .cartesian_product([
+                    (Direction4::South, 0),
+                    (Direction4::North, board.height() - 1),
+                ])
+                .map(|(x, (dir, y))| ((x as isize, y as isize), dir)),
+            (0..board.height())
+                .cartesian_product([(Direction4::East, 0), (Direction4::West, board.width() - 1)])
+                .map(|(y, (dir, x))| ((x as isize, y as isize), dir)),
+        )
+        .map(|(pos, dir)| tracer.energized(pos, dir))
+        .max()
+        .unwrap()
+    }
+}
@@ -0,0 +1,140 @@
+use strum::IntoEnumIterator;
+
+use crate::{
+    direction::Direction4,
+    parse::ParseError,
+    rect::Rect,
+    search::{dijkstra, dijkstra_with},
+    solution::{Answer, Solution, SolveError},
+    state_set::GridStateSet,
+};
+
+/// How a crucible may move: it must go at least `min_straight` blocks in a direction before it
+/// can turn, and at most `max_straight`. Unless `can_stop_early`, it also needs `min_straight`
+/// blocks before it can stop at the end.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CrucibleRules {
+    pub min_straight: u8,
+    pub max_straight: u8,
+    pub can_stop_early: bool,
+}
+
+impl CrucibleRules {
+    pub const CRUCIBLE: Self = CrucibleRules {
+        min_straight: 1,
+        max_straight: 3,
+        can_stop_early: true,
+    };
+
+    pub const ULTRA_CRUCIBLE: Self = CrucibleRules {
+        min_straight: 4,
+        max_straight: 10,
+        can_stop_early: false,
+    };
+}
+
+/// A crucible that has moved `straight` blocks in direction `dir`, or has not moved yet.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct Crucible {
+    pos: (isize, isize),
+    dir: Option<Direction4>,
+    straight: u8,
+}
+
+impl Crucible {
+    fn moves(
+        self,
+        field: &Rect<u8>,
+        rules: CrucibleRules,
+    ) -> impl Iterator<Item = (Crucible, u32)> + '_ {
+        Direction4::iter().filter_map(move |dir| {
+            let straight = match self.dir {
+                None => 1,
+                Some(d) if d == dir => self.straight.checked_add(1)?,
+                Some(d) if d == dir.opposite() => return None,
+                Some(_) if self.straight < rules.min_straight => return None,
+                Some(_) => 1,
+            };
+            if straight > rules.max_straight {
+                return None;
+            }
+            let pos = dir.advance(self.pos);
+            let &loss = field.get(pos)?;
+            Some((
+                Crucible {
+                    pos,
+                    dir: Some(dir),
+                    straight,
+                },
+                loss as u32,
+            ))
+        })
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CruciblePath {
+    pub heat_loss: u32,
+    /// Every block visited, from the top left to the bottom right corner.
+    pub path: Vec<(usize, usize)>,
+}
+
+/// Largest `max_straight` for which visited crucibles are kept in a bitset.
+const DENSE_STRAIGHT: usize = 16;
+
+/// The path from the top left to the bottom right corner of `field` with the least heat loss.
+pub fn best_path(field: &Rect<u8>, rules: CrucibleRules) -> Option<CruciblePath> {
+    let end = (field.width() as isize - 1, field.height() as isize - 1);
+    let start = Crucible {
+        pos: (0, 0),
+        dir: None,
+        straight: 0,
+    };
+    let successors = |crucible: &Crucible| crucible.moves(field, rules);
+    let goal = |crucible: &Crucible| {
+        crucible.pos == end && (rules.can_stop_early || crucible.straight >= rules.min_straight)
+    };
+    let result = if rules.max_straight as usize <= DENSE_STRAIGHT {
+        let mut seen = GridStateSet::<DENSE_STRAIGHT>::for_rect(field);
+        dijkstra_with(start, successors, goal, |crucible| match crucible.dir {
+            None => true,
+            Some(dir) => seen.insert(crucible.pos, dir, crucible.straight as usize - 1),
+        })
+    } else {
+        dijkstra(start, successors, goal)
+    }?;
+    Some(CruciblePath {
+        heat_loss: result.cost,
+        path: result
+            .path
+            .into_iter()
+            .map(|crucible| (crucible.pos.0 as usize, crucible.pos.1 as usize))
+            .collect(),
+    })
+}
+
+fn heat_loss(field: &Rect<u8>, rules: CrucibleRules) -> Result<u32, SolveError> {
+    best_path(field, rules)
+        .map(|best| best.heat_loss)
+        .ok_or_else(|| SolveError::new("no path"))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Rect<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Rect::parse_grid(input, |c| {
+            c.to_digit(10).map(|d| d as u8).ok_or("expected a digit")
+        })
+    }
+
+    fn part1(&self, field: &Self::Input) -> impl Answer {
+        heat_loss(field, CrucibleRules::CRUCIBLE)
+    }
+
+    fn part2(&self, field: &Self::Input) -> impl Answer {
+        heat_loss(field, CrucibleRules::ULTRA_CRUCIBLE)
+    }
+}
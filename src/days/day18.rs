@@ -0,0 +1,77 @@
+use regex::Regex;
+
+use crate::{
+    direction::Direction4,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+fn solution<I: Iterator<Item = (Direction4, u32)>>(iter: I) -> u64 {
+    let mut len = 0;
+    let mut area: i64 = 0;
+    let mut pos = (0, 0);
+    for (dir, dist) in iter {
+        len += dist;
+        pos = dir.advance_by(pos, dist as i32);
+        area += dist as i64
+            * match dir {
+                Direction4::South => pos.0,
+                Direction4::North => -pos.0,
+                Direction4::West => pos.1,
+                Direction4::East => -pos.1,
+            } as i64;
+    }
+    (area.unsigned_abs() + len as u64) / 2 + 1
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    /// The move each line describes in part 1 and in part 2.
+    type Input = Vec<[(Direction4, u32); 2]>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let move_re = Regex::new(r"^([RDLU]) (\d+) \(#([0-9a-f]+)\)$").unwrap();
+        input
+            .lines()
+            .map(|line| {
+                let [dir, len, color] = parse::captures(&move_re, input, line)?;
+                let dir = match dir {
+                    "R" => Direction4::East,
+                    "D" => Direction4::South,
+                    "L" => Direction4::West,
+                    "U" => Direction4::North,
+                    _ => unreachable!(),
+                };
+                let (len_hex, dir_hex) = color.split_at(color.len() - 1);
+                let color_dir = match dir_hex {
+                    "0" => Direction4::East,
+                    "1" => Direction4::South,
+                    "2" => Direction4::West,
+                    "3" => Direction4::North,
+                    _ => {
+                        return Err(ParseError::at(
+                            input,
+                            dir_hex,
+                            format!("expected a direction 0-3, found `{}`", dir_hex),
+                        ))
+                    }
+                };
+                let color_len = match len_hex {
+                    "" => 0,
+                    _ => u32::from_str_radix(len_hex, 16)
+                        .map_err(|err| ParseError::at(input, len_hex, err.to_string()))?,
+                };
+                Ok([(dir, parse::number(input, len)?), (color_dir, color_len)])
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> impl Answer {
+        solution(input.iter().map(|[step, _]| *step))
+    }
+
+    fn part2(&self, input: &Self::Input) -> impl Answer {
+        solution(input.iter().map(|[_, step]| *step))
+    }
+}
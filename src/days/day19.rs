@@ -0,0 +1,166 @@
+use std::collections::{HashMap, VecDeque};
+
+use regex::Regex;
+
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Step {
+    property: usize,
+    greater: bool,
+    constant: u32,
+    action: Action,
+}
+
+#[derive(Clone)]
+pub enum Action {
+    Accept,
+    Reject,
+    Delegate(String),
+}
+
+impl From<&str> for Action {
+    fn from(s: &str) -> Self {
+        match s {
+            "A" => Action::Accept,
+            "R" => Action::Reject,
+            _ => Action::Delegate(String::from(s)),
+        }
+    }
+}
+
+pub struct System {
+    workflows: HashMap<String, (Vec<Step>, Action)>,
+    parts: Vec<[u32; 4]>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let workflow_re = Regex::new(r"^(\w+)\{(.*),(\w+)\}$").unwrap();
+        let step_re = Regex::new(r"^([xmas])([<>])(\d+):(\w+)$").unwrap();
+        let part_re = Regex::new(r"^\{x=(\d+),m=(\d+),a=(\d+),s=(\d+)\}$").unwrap();
+        let (workflows, parts) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::eof(input, "expected a blank line after the workflows"))?;
+        let workflows = workflows
+            .lines()
+            .map(|workflow| {
+                let [name, steps, fallback] = parse::captures(&workflow_re, input, workflow)?;
+                let steps = steps
+                    .split(',')
+                    .map(|step| {
+                        let [property, op, num, action] = parse::captures(&step_re, input, step)?;
+                        Ok(Step {
+                            property: match property {
+                                "x" => 0,
+                                "m" => 1,
+                                "a" => 2,
+                                "s" => 3,
+                                _ => unreachable!(),
+                            },
+                            greater: op == ">",
+                            constant: parse::number(input, num)?,
+                            action: Action::from(action),
+                        })
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok((String::from(name), (steps, Action::from(fallback))))
+            })
+            .collect::<Result<_, _>>()?;
+        let parts = parts
+            .lines()
+            .map(|part| {
+                let captures: [&str; 4] = parse::captures(&part_re, input, part)?;
+                let mut ratings = [0; 4];
+                for (rating, n) in ratings.iter_mut().zip(captures) {
+                    *rating = parse::number(input, n)?;
+                }
+                Ok(ratings)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(System { workflows, parts })
+    }
+
+    fn part1(&self, System { workflows, parts }: &Self::Input) -> impl Answer {
+        parts
+            .iter()
+            .filter(|&part| {
+                let mut action = Action::Delegate(String::from("in"));
+                'filter: loop {
+                    match action {
+                        Action::Accept => break true,
+                        Action::Reject => break false,
+                        Action::Delegate(ref workflow) => {
+                            let (steps, fallback) = &workflows[workflow];
+                            for step in steps {
+                                if if step.greater {
+                                    part[step.property] > step.constant
+                                } else {
+                                    part[step.property] < step.constant
+                                } {
+                                    action = step.action.clone();
+                                    continue 'filter;
+                                }
+                            }
+                            action = fallback.clone();
+                        }
+                    }
+                }
+            })
+            .flatten()
+            .sum::<u32>()
+    }
+
+    fn part2(&self, System { workflows, .. }: &Self::Input) -> impl Answer {
+        let mut accepted = 0;
+        let mut queue = VecDeque::new();
+        fn volume(min: [u32; 4], max: [u32; 4]) -> u64 {
+            min.into_iter()
+                .zip(max)
+                .map(|(min, max)| (max - min + 1) as u64)
+                .product()
+        }
+        let mut act = |queue: &mut VecDeque<_>, action: &Action, min, max| match action {
+            Action::Accept => accepted += volume(min, max),
+            Action::Reject => (),
+            Action::Delegate(workflow) => queue.push_back((min, max, workflow.clone())),
+        };
+        queue.push_back(([1, 1, 1, 1], [4000, 4000, 4000, 4000], String::from("in")));
+        'queue: while let Some((mut min, mut max, workflow)) = queue.pop_front() {
+            let (steps, fallback) = &workflows[&workflow];
+            for step in steps {
+                if step.greater {
+                    if max[step.property] > step.constant {
+                        let mut min = min;
+                        min[step.property] = step.constant + 1;
+                        act(&mut queue, &step.action, min, max)
+                    }
+                    if min[step.property] <= step.constant {
+                        max[step.property] = step.constant;
+                    } else {
+                        continue 'queue;
+                    }
+                } else {
+                    if min[step.property] < step.constant {
+                        let mut max = max;
+                        max[step.property] = step.constant - 1;
+                        act(&mut queue, &step.action, min, max)
+                    }
+                    if max[step.property] >= step.constant {
+                        min[step.property] = step.constant;
+                    } else {
+                        continue 'queue;
+                    }
+                }
+            }
+            act(&mut queue, fallback, min, max)
+        }
+        accepted
+    }
+}
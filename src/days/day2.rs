@@ -0,0 +1,102 @@
+use regex::Regex;
+
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+#[derive(Clone, Copy, Debug)]
+struct Round {
+    red: i32,
+    green: i32,
+    blue: i32,
+}
+
+const ZERO_ROUND: Round = Round {
+    red: 0,
+    green: 0,
+    blue: 0,
+};
+
+fn max_rounds(r1: Round, r2: Round) -> Round {
+    Round {
+        red: Ord::max(r1.red, r2.red),
+        green: Ord::max(r1.green, r2.green),
+        blue: Ord::max(r1.blue, r2.blue),
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: usize,
+    rounds: Vec<Round>,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let game_re = Regex::new(r"^Game (\d+):(.*)$").unwrap();
+        let ball_re = Regex::new(r"^\s*(\d+)\s+(\w+)\s*$").unwrap();
+        input
+            .lines()
+            .map(|line| {
+                let [id, rest] = parse::captures(&game_re, input, line)?;
+                let rounds = rest
+                    .split(';')
+                    .map(|r| {
+                        let mut round = ZERO_ROUND;
+                        for ball in r.split(',') {
+                            let [num, color] = parse::captures(&ball_re, input, ball)?;
+                            let num: i32 = parse::number(input, num)?;
+                            match color {
+                                "red" => round.red += num,
+                                "green" => round.green += num,
+                                "blue" => round.blue += num,
+                                _ => {
+                                    return Err(ParseError::at(
+                                        input,
+                                        color,
+                                        format!("unknown color `{}`", color),
+                                    ))
+                                }
+                            }
+                        }
+                        Ok(round)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Ok(Game {
+                    id: parse::number(input, id)?,
+                    rounds,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> impl Answer {
+        input
+            .iter()
+            .filter(|game| {
+                game.rounds
+                    .iter()
+                    .all(|r| r.red <= 12 && r.green <= 13 && r.blue <= 14)
+            })
+            .map(|game| game.id)
+            .sum::<usize>()
+    }
+
+    fn part2(&self, input: &Self::Input) -> impl Answer {
+        input
+            .iter()
+            .map(|game| {
+                let Round { red, green, blue } = game
+                    .rounds
+                    .iter()
+                    .fold(ZERO_ROUND, |r1, &r2| max_rounds(r1, r2));
+                red * green * blue
+            })
+            .sum::<i32>()
+    }
+}
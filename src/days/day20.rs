@@ -0,0 +1,297 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    convert::identity,
+    mem,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    numbers::checked_lcm,
+    parse::ParseError,
+    solution::{Answer, Solution, SolveError},
+};
+
+#[derive(PartialEq, Eq, Debug)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    Dummy,
+}
+
+#[derive(Debug)]
+struct Module {
+    kind: ModuleType,
+    inputs: Vec<usize>,
+    outputs: Vec<usize>,
+}
+
+#[derive(Debug)]
+enum ModuleState {
+    Broadcaster,
+    FlipFlop(bool),
+    Conjunction(Vec<bool>),
+    Dummy,
+}
+
+fn init_state(module: &Module) -> ModuleState {
+    match module.kind {
+        ModuleType::Broadcaster => ModuleState::Broadcaster,
+        ModuleType::FlipFlop => ModuleState::FlipFlop(false),
+        ModuleType::Conjunction => ModuleState::Conjunction(vec![false; module.inputs.len()]),
+        ModuleType::Dummy => ModuleState::Dummy,
+    }
+}
+
+fn handle_pulse(
+    modules: &[Module],
+    state: &mut [ModuleState],
+    pulses: &mut VecDeque<(bool, usize, usize)>,
+    high: bool,
+    from: usize,
+    current: usize,
+) {
+    match &mut state[current] {
+        ModuleState::Broadcaster => {
+            for &to in &modules[current].outputs {
+                pulses.push_back((high, current, to));
+            }
+        }
+        ModuleState::FlipFlop(state) => {
+            if !high {
+                *state = !*state;
+                for &to in &modules[current].outputs {
+                    pulses.push_back((*state, current, to));
+                }
+            }
+        }
+        ModuleState::Conjunction(conj) => {
+            let index = modules[current]
+                .inputs
+                .iter()
+                .position(|&input| input == from)
+                .unwrap();
+            conj[index] = high;
+            let output = !conj.iter().copied().all(identity);
+            for &to in &modules[current].outputs {
+                pulses.push_back((output, current, to));
+            }
+        }
+        ModuleState::Dummy => (),
+    }
+}
+
+/// `rx` is fed by a conjunction of inverters, each of which inverts a conjunction that matches a
+/// binary counter of flip-flops. Returns the count at which each counter matches, or `None` if
+/// the network is not shaped like that.
+fn counter_masks(
+    Network {
+        modules, indices, ..
+    }: &Network,
+) -> Option<Vec<u64>> {
+    let is = |index: usize, kind: ModuleType| modules[index].kind == kind;
+    let rx = &modules[*indices.get("rx")?];
+    if rx.kind != ModuleType::Dummy {
+        return None;
+    }
+    let conj = match rx.inputs[..] {
+        [input] if is(input, ModuleType::Conjunction) => &modules[input],
+        _ => return None,
+    };
+    conj.inputs
+        .iter()
+        .copied()
+        .map(|inverter_idx| {
+            let inverter = &modules[inverter_idx];
+            if inverter.kind != ModuleType::Conjunction {
+                return None;
+            }
+            let matcher = match inverter.inputs[..] {
+                [input] if is(input, ModuleType::Conjunction) => &modules[input],
+                _ => return None,
+            };
+            let bits: HashSet<usize> = matcher
+                .inputs
+                .iter()
+                .copied()
+                .chain(
+                    matcher
+                        .outputs
+                        .iter()
+                        .copied()
+                        .filter(|&bit| bit != inverter_idx),
+                )
+                .collect();
+            if !bits.iter().all(|&bit| is(bit, ModuleType::FlipFlop)) {
+                return None;
+            }
+            let bits: Vec<usize> = {
+                let mut seq = VecDeque::new();
+                let center = bits.iter().copied().next()?;
+                seq.push_back(center);
+                let mut bit = center;
+                while let Some(&b) = modules[bit].outputs.iter().find(|&i| bits.contains(i)) {
+                    if seq.len() > bits.len() {
+                        return None;
+                    }
+                    seq.push_back(b);
+                    bit = b;
+                }
+                let mut bit = center;
+                while let Some(&b) = modules[bit].inputs.iter().find(|&i| bits.contains(i)) {
+                    if seq.len() > bits.len() {
+                        return None;
+                    }
+                    seq.push_front(b);
+                    bit = b;
+                }
+                if seq.len() != bits.len() || bits.len() >= 64 {
+                    return None;
+                }
+                seq.into()
+            };
+            let test_mask = bits
+                .iter()
+                .copied()
+                .enumerate()
+                .filter_map(|(i, bit)| matcher.inputs.contains(&bit).then_some(1 << i))
+                .sum::<u64>();
+            let add_mask = bits
+                .iter()
+                .copied()
+                .enumerate()
+                .filter_map(|(i, bit)| matcher.outputs.contains(&bit).then_some(1 << i))
+                .sum::<u64>();
+            (test_mask + add_mask == 1 << bits.len()).then_some(test_mask)
+        })
+        .collect()
+}
+
+pub struct Network {
+    modules: Vec<Module>,
+    indices: HashMap<String, usize>,
+    broadcaster: usize,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Network;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut names = Vec::new();
+        let mut modules: Vec<Result<Module, Vec<usize>>> = Vec::new();
+        let mut indices = HashMap::new();
+        let mut resolve =
+            |modules: &mut Vec<_>, names: &mut Vec<_>, name: &str| match indices.get(name) {
+                None => {
+                    let index = modules.len();
+                    indices.insert(String::from(name), index);
+                    names.push(String::from(name));
+                    modules.push(Err(Vec::new()));
+                    index
+                }
+                Some(&index) => index,
+            };
+        for line in input.lines() {
+            let (name, out) = line
+                .split_once(" -> ")
+                .ok_or_else(|| ParseError::at(input, line, "expected a module and its outputs"))?;
+            let (name, kind) = match name {
+                "broadcaster" => (name, ModuleType::Broadcaster),
+                _ if name.starts_with('%') => (&name[1..], ModuleType::FlipFlop),
+                _ if name.starts_with('&') => (&name[1..], ModuleType::Conjunction),
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        name,
+                        format!("unknown module type `{}`", name),
+                    ))
+                }
+            };
+            let index = resolve(&mut modules, &mut names, name);
+            let mut outputs = Vec::new();
+            for output in out.split(", ") {
+                let output = resolve(&mut modules, &mut names, output);
+                outputs.push(output);
+                match &mut modules[output] {
+                    Ok(module) => module.inputs.push(index),
+                    Err(inputs) => inputs.push(index),
+                }
+            }
+            match &mut modules[index] {
+                Ok(_) => {
+                    return Err(ParseError::at(
+                        input,
+                        name,
+                        format!("module `{}` is defined twice", name),
+                    ))
+                }
+                Err(inputs) => {
+                    modules[index] = Ok(Module {
+                        kind,
+                        inputs: mem::take(inputs),
+                        outputs,
+                    })
+                }
+            }
+        }
+        let broadcaster = *indices
+            .get("broadcaster")
+            .ok_or_else(|| ParseError::eof(input, "expected a `broadcaster` module"))?;
+        Ok(Network {
+            modules: modules
+                .into_iter()
+                .map(|res| match res {
+                    Ok(module) => module,
+                    Err(inputs) => Module {
+                        kind: ModuleType::Dummy,
+                        inputs,
+                        outputs: Vec::new(),
+                    },
+                })
+                .collect(),
+            indices,
+            broadcaster,
+        })
+    }
+
+    fn part1(
+        &self,
+        Network {
+            modules,
+            broadcaster,
+            ..
+        }: &Self::Input,
+    ) -> impl Answer {
+        let broadcaster = *broadcaster;
+        let mut state = modules.iter().map(init_state).collect_vec();
+        let mut high_count: usize = 0;
+        let mut low_count: usize = 0;
+        let mut pulses = VecDeque::new();
+        for _ in 0..1000 {
+            pulses.push_back((false, broadcaster, broadcaster));
+            while let Some((high, from, current)) = pulses.pop_front() {
+                if high {
+                    high_count += 1;
+                } else {
+                    low_count += 1;
+                }
+                handle_pulse(modules, &mut state, &mut pulses, high, from, current)
+            }
+        }
+        high_count * low_count
+    }
+
+    fn part2(&self, network: &Self::Input) -> impl Answer {
+        counter_masks(network)
+            .ok_or_else(|| SolveError::new("no counters feeding `rx`"))
+            .and_then(|masks| {
+                masks
+                    .into_iter()
+                    .try_fold(1, checked_lcm)
+                    .ok_or_else(|| SolveError::new("press count overflows"))
+            })
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use crate::{
+    parse::ParseError,
+    poly::{degree, extrapolate},
+    rect::Rect,
+    solution::{Answer, Solution, SolveError},
+};
+
+fn start_pos(board: &Rect<char>) -> Option<(isize, isize)> {
+    board
+        .cells()
+        .find_map(|(x, y, &c)| (c == 'S').then_some((x as isize, y as isize)))
+}
+
+fn next_steps<'a, I: Iterator<Item = (isize, isize)> + 'a>(
+    board: &'a Rect<char>,
+    cells: I,
+) -> impl Iterator<Item = (isize, isize)> + 'a {
+    cells.flat_map(move |pos| {
+        board
+            .neighbors4(pos)
+            .filter_map(|(_, pos, &c)| (c != '#').then_some(pos))
+    })
+}
+
+fn next_steps_wrapping<'a, I: Iterator<Item = (isize, isize)> + 'a>(
+    board: &'a Rect<char>,
+    cells: I,
+) -> impl Iterator<Item = (isize, isize)> + 'a {
+    cells.flat_map(move |pos| {
+        board
+            .neighbors4_wrapping(pos)
+            .filter_map(|(_, pos, &c)| (c != '#').then_some(pos))
+    })
+}
+
+pub struct Garden {
+    board: Rect<char>,
+    start: (isize, isize),
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let board = Rect::try_from(input)?;
+        let start =
+            start_pos(&board).ok_or_else(|| ParseError::eof(input, "expected a start tile `S`"))?;
+        Ok(Garden { board, start })
+    }
+
+    fn part1(&self, Garden { board, start }: &Self::Input) -> impl Answer {
+        let mut cells: HashSet<(isize, isize)> = [*start].into();
+        for _ in 0..64 {
+            cells = next_steps(board, cells.into_iter()).collect();
+        }
+        cells.len()
+    }
+
+    fn part2(&self, Garden { board, start }: &Self::Input) -> impl Answer {
+        let mut perimeter: HashSet<(isize, isize)> = [*start].into();
+        let mut prev = HashSet::new();
+        let mut firsts = Vec::new();
+        let mut period = 0;
+        let period = loop {
+            let result = next_steps_wrapping(board, perimeter.iter().copied())
+                .filter(|pos| !prev.contains(pos))
+                .collect();
+            prev = perimeter;
+            perimeter = result;
+            if perimeter.is_empty() {
+                return Err(SolveError::new("the start is fenced in"));
+            }
+
+            firsts.push(perimeter.len());
+            period += 1;
+
+            let num = perimeter
+                .iter()
+                .copied()
+                .filter(|&pos| board.get_wrapping(pos) == Some(&'S'))
+                .count();
+            if num > 0 {
+                if num != 4 {
+                    return Err(SolveError::new(
+                        "copies of the start are not first reached straight across the garden",
+                    ));
+                }
+                break period;
+            }
+        };
+        let mut seconds = Vec::new();
+        for _ in 0..period {
+            let result = next_steps_wrapping(board, perimeter.iter().copied())
+                .filter(|pos| !prev.contains(pos))
+                .collect();
+            prev = perimeter;
+            perimeter = result;
+
+            seconds.push(perimeter.len());
+        }
+        // The number of new cells reached after `n` steps grows linearly from one period to the
+        // next, so the number reachable after `n` steps is quadratic in `n / (2 * period)`.
+        let new_cells = |n: usize| -> i64 {
+            if n == 0 {
+                1
+            } else {
+                let rem = (n - 1) % period;
+                let quot = (n - 1) / period;
+                extrapolate(&[firsts[rem] as i64, seconds[rem] as i64], quot as i64)
+            }
+        };
+        let reachable = |steps: usize| (0..=steps).rev().step_by(2).map(new_cells).sum::<i64>();
+        let steps = 26501365;
+        let cycle = 2 * period;
+        let samples = (0..4)
+            .map(|k| reachable(steps % cycle + k * cycle))
+            .collect::<Vec<_>>();
+        if degree(&samples).is_none_or(|d| d > 2) {
+            return Err(SolveError::new("reachable plots do not grow quadratically"));
+        }
+        Ok(extrapolate(&samples, (steps / cycle) as i64))
+    }
+}
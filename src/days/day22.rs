@@ -0,0 +1,127 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    iter::repeat_with,
+};
+
+use itertools::Itertools;
+
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+type Point = (u32, u32, u32);
+
+fn settle(blocks: &[(Point, Point)]) -> (Vec<(Point, Point)>, Vec<HashSet<usize>>) {
+    let mut blocks = blocks.to_vec();
+    blocks.sort_unstable_by_key(|&((_, _, z), _)| z);
+    let mut height_map: HashMap<(u32, u32), (u32, usize)> = HashMap::new();
+    let mut rests_on = Vec::new();
+    for (i, &mut ((x, y, ref mut z), (size_x, size_y, size_z))) in blocks.iter_mut().enumerate() {
+        let mut rests = HashSet::new();
+        let mut min_z = 0;
+        for x in x..x + size_x {
+            for y in y..y + size_y {
+                let Some(&(z, j)) = height_map.get(&(x, y)) else {
+                    continue;
+                };
+                match Ord::cmp(&z, &min_z) {
+                    Ordering::Greater => {
+                        min_z = z;
+                        rests.clear();
+                        rests.insert(j);
+                    }
+                    Ordering::Equal => {
+                        rests.insert(j);
+                    }
+                    Ordering::Less => continue,
+                }
+            }
+        }
+        *z = min_z + 1;
+        for x in x..x + size_x {
+            for y in y..y + size_y {
+                height_map.insert((x, y), (min_z + size_z, i));
+            }
+        }
+        rests_on.push(rests);
+    }
+    (blocks, rests_on)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<(Point, Point)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let point = |p: &str| -> Result<[u32; 3], ParseError> {
+            p.split(',')
+                .map(|n| parse::number(input, n))
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into()
+                .map_err(|_| ParseError::at(input, p, "expected three coordinates"))
+        };
+        input
+            .lines()
+            .map(|line| {
+                let (p1, p2) = line
+                    .split_once('~')
+                    .ok_or_else(|| ParseError::at(input, line, "expected two corners"))?;
+                let [x1, y1, z1] = point(p1)?;
+                let [x2, y2, z2] = point(p2)?;
+                Ok((
+                    (Ord::min(x1, x2), Ord::min(y1, y2), Ord::min(z1, z2)),
+                    (
+                        x1.abs_diff(x2) + 1,
+                        y1.abs_diff(y2) + 1,
+                        z1.abs_diff(z2) + 1,
+                    ),
+                ))
+            })
+            .collect()
+    }
+
+    fn part1(&self, blocks: &Self::Input) -> impl Answer {
+        let (blocks, rests_on) = settle(blocks);
+        blocks.len()
+            - rests_on
+                .iter()
+                .filter_map(|r| match r.iter().collect_vec()[..] {
+                    [on] => Some(on),
+                    _ => None,
+                })
+                .collect::<HashSet<_>>()
+                .len()
+    }
+
+    fn part2(&self, blocks: &Self::Input) -> impl Answer {
+        let (blocks, rests_on) = settle(blocks);
+        let mut supports: Vec<_> = repeat_with(HashSet::new).take(blocks.len()).collect();
+        for (i, rests) in rests_on.iter().enumerate() {
+            for &j in rests {
+                supports[j].insert(i);
+            }
+        }
+
+        (0..blocks.len())
+            .map(|seed| {
+                let mut fallen = HashSet::new();
+                let mut stack = Vec::new();
+                stack.push(seed);
+                while let Some(i) = stack.pop() {
+                    if !fallen.insert(i) {
+                        continue;
+                    }
+                    for &j in &supports[i] {
+                        if rests_on[j].iter().all(|k| fallen.contains(k)) {
+                            stack.push(j)
+                        }
+                    }
+                }
+                (fallen.len() - 1) as u32
+            })
+            .sum::<u32>()
+    }
+}
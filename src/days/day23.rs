@@ -0,0 +1,180 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use itertools::Itertools;
+use strum::IntoEnumIterator;
+
+use crate::{
+    direction::Direction4,
+    parse::ParseError,
+    rect::Rect,
+    solution::{Answer, Solution},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Wall,
+    Empty,
+    Only(Direction4),
+}
+
+/// The longest hike from `start` to `finish`. The start and finish are junctions 0 and 1.
+fn solution(field: &Rect<Cell>, start: (isize, isize), finish: (isize, isize)) -> usize {
+    let junctions: Vec<(isize, isize)> = [start, finish]
+        .into_iter()
+        .chain(field.cells().filter_map(|(x, y, c)| {
+            match c {
+                Cell::Wall => None,
+                _ => (field
+                    .neighbors4((x, y))
+                    .filter(|&(_, _, c)| *c != Cell::Wall)
+                    .count()
+                    != 2)
+                    .then_some((x as isize, y as isize))
+                    .filter(|&pos| pos != start && pos != finish),
+            }
+        }))
+        .collect();
+
+    let graph: Vec<HashMap<usize, usize>> = junctions
+        .iter()
+        .copied()
+        .map(|pos| {
+            Direction4::iter()
+                .filter_map(|mut dir| {
+                    let mut pos = dir.advance(pos);
+                    let mut distance = 1;
+                    loop {
+                        if let Some(i) = junctions.iter().copied().position(|p| p == pos) {
+                            break Some((i, distance));
+                        }
+                        match field.get(pos) {
+                            None => break None,
+                            Some(Cell::Wall) => break None,
+                            Some(&Cell::Only(d)) => {
+                                if d != dir {
+                                    break None;
+                                }
+                            }
+                            Some(Cell::Empty) => match field
+                                .neighbors4(pos)
+                                .find(|&(d, _, c)| d != dir.opposite() && *c != Cell::Wall)
+                            {
+                                Some((d, _, _)) => dir = d,
+                                None => break None,
+                            },
+                        }
+                        pos = dir.advance(pos);
+                        distance += 1;
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let start = 0;
+    let mut finish = 1;
+    let mut finish_len = 0;
+    while let [(node, distance)] = graph
+        .iter()
+        .enumerate()
+        .filter_map(|(i, m)| m.get(&finish).map(|&distance| (i, distance)))
+        .collect_vec()[..]
+    {
+        finish = node;
+        finish_len += distance;
+    }
+
+    let mut journeys = VecDeque::new();
+    journeys.push_back((start, 0, HashSet::new()));
+    let mut max_len = 0;
+    while let Some((i, len, mut seen)) = journeys.pop_front() {
+        if i == finish {
+            max_len = Ord::max(max_len, len);
+            continue;
+        }
+        seen.insert(i);
+        for (&j, &distance) in &graph[i] {
+            if !seen.contains(&j) {
+                journeys.push_back((j, len + distance, seen.clone()))
+            }
+        }
+    }
+    max_len + finish_len
+}
+
+/// The only path tile in row `y`, which is `row` in `input`.
+fn single_path_tile(
+    input: &str,
+    field: &Rect<Cell>,
+    y: usize,
+    row: &str,
+) -> Result<(isize, isize), ParseError> {
+    match (0..field.width())
+        .filter(|&x| field[(x, y)] != Cell::Wall)
+        .collect::<Vec<_>>()[..]
+    {
+        [x] => Ok((x as isize, y as isize)),
+        _ => Err(ParseError::at(input, row, "expected exactly one path tile")),
+    }
+}
+
+pub struct Trails {
+    field: Rect<Cell>,
+    start: (isize, isize),
+    finish: (isize, isize),
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Trails;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let field = Rect::parse_grid(input, |c| match c {
+            '#' => Ok(Cell::Wall),
+            '.' => Ok(Cell::Empty),
+            '>' => Ok(Cell::Only(Direction4::East)),
+            'v' => Ok(Cell::Only(Direction4::South)),
+            '<' => Ok(Cell::Only(Direction4::West)),
+            '^' => Ok(Cell::Only(Direction4::North)),
+            _ => Err("expected one of `#.>v<^`"),
+        })?;
+        let (first, last) = match input.lines().collect::<Vec<_>>()[..] {
+            [first, .., last] => (first, last),
+            _ => return Err(ParseError::eof(input, "expected at least two rows")),
+        };
+        Ok(Trails {
+            start: single_path_tile(input, &field, 0, first)?,
+            finish: single_path_tile(input, &field, field.height() - 1, last)?,
+            field,
+        })
+    }
+
+    fn part1(
+        &self,
+        Trails {
+            field,
+            start,
+            finish,
+        }: &Self::Input,
+    ) -> impl Answer {
+        solution(field, *start, *finish)
+    }
+
+    fn part2(
+        &self,
+        Trails {
+            field,
+            start,
+            finish,
+        }: &Self::Input,
+    ) -> impl Answer {
+        let mut field = field.clone();
+        for (_, _, cell) in field.cells_mut() {
+            if matches!(cell, Cell::Only(_)) {
+                *cell = Cell::Empty
+            }
+        }
+        solution(&field, *start, *finish)
+    }
+}
@@ -0,0 +1,127 @@
+use regex::Regex;
+
+use crate::{
+    bigint::BigInt,
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+type Vector = (i128, i128, i128);
+
+const MIN: i128 = 200000000000000;
+const MAX: i128 = 400000000000000;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<(Vector, Vector)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let line_re =
+            Regex::new(r"^([\d-]+), *([\d-]+), *([\d-]+) *@ *([\d-]+), *([\d-]+), *([\d-]+)$")
+                .unwrap();
+        input
+            .lines()
+            .map(|line| {
+                let captures: [&str; 6] = parse::captures(&line_re, input, line)?;
+                let mut coords = [0; 6];
+                for (coord, n) in coords.iter_mut().zip(captures) {
+                    *coord = parse::number(input, n)?;
+                }
+                let [x, y, z, vx, vy, vz] = coords;
+                Ok(((x, y, z), (vx, vy, vz)))
+            })
+            .collect()
+    }
+
+    fn part1(&self, input: &Self::Input) -> impl Answer {
+        input
+            .iter()
+            .copied()
+            .enumerate()
+            .flat_map(|(i, (p1, v1))| {
+                input[i + 1..]
+                    .iter()
+                    .copied()
+                    .map(move |(p2, v2)| (p1, v1, p2, v2))
+            })
+            .filter(
+                |&((x1, y1, _), (vx1, vy1, _), (x2, y2, _), (vx2, vy2, _))| {
+                    let [x1, y1, vx1, vy1, x2, y2, vx2, vy2] =
+                        [x1, y1, vx1, vy1, x2, y2, vx2, vy2].map(BigInt::from);
+                    let det = &vy1 * &vx2 - &vx1 * &vy2;
+                    if det.is_zero() {
+                        return false;
+                    }
+                    // Both paths cross at times t1 / det and t2 / det.
+                    let sign = det.signum();
+                    let t1 = ((&y2 - &y1) * &vx2 - (&x2 - &x1) * &vy2) * &sign;
+                    let t2 = ((&y2 - &y1) * &vx1 - (&x2 - &x1) * &vy1) * &sign;
+                    let det = det.abs();
+                    let (min, max) = (BigInt::from(MIN), BigInt::from(MAX));
+                    let (x, y) = (&t1 * &vx1, &t1 * &vy1);
+                    !t1.is_negative()
+                        && !t2.is_negative()
+                        && x >= (&min - &x1) * &det
+                        && x <= (&max - &x1) * &det
+                        && y >= (&min - &y1) * &det
+                        && y <= (&max - &y1) * &det
+                },
+            )
+            .count()
+    }
+
+    fn part2(&self, input: &Self::Input) -> impl Answer {
+        let ((x, y, z), _) = (0i128..)
+            .flat_map(|total| {
+                (-total..=total).flat_map(move |vx| {
+                    (vx.abs() - total..=total - vx.abs()).flat_map(move |vy| {
+                        [vx.abs() + vy.abs() - total, total - vx.abs() - vy.abs()]
+                            .map(move |vz| (vx, vy, vz))
+                    })
+                })
+            })
+            .filter_map(|(vx, vy, vz)| {
+                let ((x1, y1, z1), (vx1, vy1, vz1), (x2, y2, z2), (vx2, vy2, vz2), det) = input
+                    .iter()
+                    .copied()
+                    .enumerate()
+                    .flat_map(|(i, (p1, v1))| {
+                        input[i + 1..]
+                            .iter()
+                            .copied()
+                            .map(move |(p2, v2)| (p1, v1, p2, v2))
+                    })
+                    .find_map(|(p1, v1 @ (vx1, vy1, _), p2, v2 @ (vx2, vy2, _))| {
+                        let det = (vx1 - vx) * (vy - vy2) - (vy1 - vy) * (vx - vx2);
+                        (det != 0).then_some((p1, v1, p2, v2, det))
+                    })
+                    .unwrap();
+                let t1 = ((vy - vy2) * (x2 - x1) + (vx2 - vx) * (y2 - y1)) * det.signum();
+                let t2 = ((vy - vy1) * (x2 - x1) + (vx1 - vx) * (y2 - y1)) * det.signum();
+                let det = det.abs();
+                if t1 * (vz1 - vz) + t2 * (vz - vz2) != (z2 - z1) * det {
+                    return None;
+                }
+                if t1 * (vx1 - vx) % det != 0
+                    || t1 * (vy1 - vy) % det != 0
+                    || t1 * (vz1 - vz) % det != 0
+                {
+                    return None;
+                }
+                let x = x1 + t1 * (vx1 - vx) / det;
+                let y = y1 + t1 * (vy1 - vy) / det;
+                let z = z1 + t1 * (vz1 - vz) / det;
+                if !input.iter().all(|&((xn, yn, zn), (vxn, vyn, vzn))| {
+                    (xn - x) * (vyn - vy) == (yn - y) * (vxn - vx)
+                        && (xn - x) * (vzn - vz) == (zn - z) * (vxn - vx)
+                }) {
+                    return None;
+                };
+                Some(((x, y, z), (vx, vy, vz)))
+            })
+            .next()
+            .unwrap();
+        x + y + z
+    }
+}
@@ -0,0 +1,72 @@
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+
+use crate::{
+    parse::ParseError,
+    rect::Rect,
+    solution::{Answer, Solution},
+};
+
+type NumberRange = (RangeInclusive<usize>, usize);
+
+fn find_number_range(field: &Rect<char>, (x, y): (usize, usize)) -> Option<NumberRange> {
+    if !field.get((x, y)).is_some_and(char::is_ascii_digit) {
+        return None;
+    }
+    let mut minx = x;
+    while minx > 0 && field.get((minx - 1, y)).is_some_and(char::is_ascii_digit) {
+        minx -= 1
+    }
+    let mut maxx = x;
+    while field.get((maxx + 1, y)).is_some_and(char::is_ascii_digit) {
+        maxx += 1
+    }
+    Some((minx..=maxx, y))
+}
+
+fn read_number_range(field: &Rect<char>, (range, y): NumberRange) -> u32 {
+    field.row(y)[range]
+        .iter()
+        .copied()
+        .fold(0, |acc, d| acc * 10 + (d.to_digit(10).unwrap()))
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Rect<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Rect::try_from(input)
+    }
+
+    fn part1(&self, field: &Self::Input) -> impl Answer {
+        field
+            .cells()
+            .filter_map(|(x, y, &c)| (c.is_ascii_punctuation() && c != '.').then_some((x, y)))
+            .flat_map(|pos| field.neighbors8(pos).map(|(_, pos, _)| pos))
+            .filter_map(|p| find_number_range(field, p))
+            .unique()
+            .map(|range| read_number_range(field, range))
+            .sum::<u32>()
+    }
+
+    fn part2(&self, field: &Self::Input) -> impl Answer {
+        field
+            .cells()
+            .filter_map(|(x, y, &c)| (c == '*').then_some((x, y)))
+            .filter_map(|p| {
+                let [p1, p2] = field
+                    .neighbors8(p)
+                    .map(|(_, p, _)| p)
+                    .filter_map(|p| find_number_range(field, p))
+                    .unique()
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .ok()?;
+                Some(read_number_range(field, p1) * read_number_range(field, p2))
+            })
+            .sum::<u32>()
+    }
+}
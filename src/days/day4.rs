@@ -0,0 +1,58 @@
+use regex::Regex;
+
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Card {
+    winning: Vec<i32>,
+    have: Vec<i32>,
+}
+
+impl Card {
+    fn matches(&self) -> usize {
+        self.have
+            .iter()
+            .filter(|n| self.winning.contains(n))
+            .count()
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let card_re = Regex::new(r"^Card\s+\d+:(.*)\|(.*)$").unwrap();
+        input
+            .lines()
+            .map(|line| {
+                let [winning, have] = parse::captures(&card_re, input, line)?;
+                Ok(Card {
+                    winning: parse::numbers(input, winning)?,
+                    have: parse::numbers(input, have)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(&self, cards: &Self::Input) -> impl Answer {
+        cards
+            .iter()
+            .map(|card| 1 << card.matches() >> 1)
+            .sum::<usize>()
+    }
+
+    fn part2(&self, cards: &Self::Input) -> impl Answer {
+        let mut copies = vec![1; cards.len()];
+        for (i, card) in cards.iter().enumerate() {
+            let copy = copies[i];
+            for j in 1..=card.matches() {
+                copies[i + j] += copy
+            }
+        }
+        copies.into_iter().sum::<usize>()
+    }
+}
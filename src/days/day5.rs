@@ -0,0 +1,111 @@
+use std::{
+    cmp::{max, min},
+    ops::Range,
+};
+
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct MapEntry {
+    source: Range<i64>,
+    offset: i64,
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<Vec<MapEntry>>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut it = input.split("\n\n");
+        let seeds = it
+            .next()
+            .unwrap()
+            .split_ascii_whitespace()
+            .skip(1)
+            .map(|seed| parse::number(input, seed))
+            .collect::<Result<_, _>>()?;
+        let maps = it
+            .map(|m| {
+                m.lines()
+                    .skip(1)
+                    .map(|l| {
+                        let [dest, src, len]: [i64; 3] = parse::numbers(input, l)?
+                            .try_into()
+                            .map_err(|_| ParseError::at(input, l, "expected three numbers"))?;
+                        Ok(MapEntry {
+                            source: src..src + len,
+                            offset: dest - src,
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(&self, Almanac { seeds, maps }: &Self::Input) -> impl Answer {
+        seeds
+            .iter()
+            .map(|&seed| {
+                maps.iter().fold(seed, |seed, m| {
+                    for e in m {
+                        if e.source.contains(&seed) {
+                            return seed + e.offset;
+                        }
+                    }
+                    seed
+                })
+            })
+            .min()
+            .unwrap()
+    }
+
+    fn part2(&self, Almanac { seeds, maps }: &Self::Input) -> impl Answer {
+        maps.iter()
+            .fold(
+                seeds
+                    .chunks_exact(2)
+                    .map(|chunk| match chunk {
+                        &[start, len] => start..start + len,
+                        _ => panic!(""),
+                    })
+                    .collect::<Vec<_>>(),
+                |ranges, map| {
+                    let mut mapped = Vec::new();
+                    let mut input = ranges;
+                    for e in map {
+                        let mut output = Vec::new();
+                        for range in input {
+                            if range.start < e.source.end && range.end > e.source.start {
+                                mapped.push(
+                                    max(range.start, e.source.start) + e.offset
+                                        ..min(range.end, e.source.end) + e.offset,
+                                )
+                            }
+                            if range.start < e.source.start {
+                                output.push(range.start..min(range.end, e.source.start))
+                            }
+                            if range.end > e.source.end {
+                                output.push(max(range.start, e.source.end)..range.end)
+                            }
+                        }
+                        input = output
+                    }
+                    mapped.extend(input);
+                    mapped
+                },
+            )
+            .into_iter()
+            .map(|r| r.start)
+            .min()
+            .unwrap()
+    }
+}
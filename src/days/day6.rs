@@ -0,0 +1,77 @@
+use crate::{
+    numbers::{isqrt, Ratio},
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
+pub struct Races {
+    times: Vec<i64>,
+    time: i64,
+    distances: Vec<i64>,
+    distance: i64,
+}
+
+/// The number of ways to hold the button for `h` ms with `h * (t - h) > d`, that is
+/// `(2 * h - t)^2 < t^2 - 4 * d`. The discriminant is computed in `i128`, where it cannot
+/// overflow.
+fn solve((t, d): (i64, i64)) -> i64 {
+    let (t, d) = (t as i128, d as i128);
+    let disc = t * t - 4 * d;
+    if disc <= 0 {
+        return 0;
+    }
+    let s = isqrt(disc);
+    // The largest `s` with `s^2 < disc`.
+    let s = if s * s == disc { s - 1 } else { s };
+    let min = Ratio::new(t - s, 2).ceil();
+    let max = Ratio::new(t + s, 2).floor();
+    (max - min + 1).max(0) as i64
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Races;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+        let mut get_number_line = |what| {
+            let line = lines
+                .next()
+                .ok_or_else(|| ParseError::eof(input, format!("expected a line of {}", what)))?;
+            let numbers = line
+                .split_ascii_whitespace()
+                .skip(1)
+                .map(|n| parse::number(input, n))
+                .collect::<Result<_, _>>()?;
+            let mut digits = String::from(line);
+            digits.retain(|c| c.is_ascii_digit());
+            let kerned = digits
+                .parse()
+                .map_err(|err| ParseError::at(input, line, format!("invalid number: {}", err)))?;
+            Ok((numbers, kerned))
+        };
+        let (times, time) = get_number_line("times")?;
+        let (distances, distance) = get_number_line("distances")?;
+        Ok(Races {
+            times,
+            time,
+            distances,
+            distance,
+        })
+    }
+
+    fn part1(&self, races: &Self::Input) -> impl Answer {
+        races
+            .times
+            .iter()
+            .copied()
+            .zip(races.distances.iter().copied())
+            .map(solve)
+            .product::<i64>()
+    }
+
+    fn part2(&self, races: &Self::Input) -> impl Answer {
+        solve((races.time, races.distance))
+    }
+}
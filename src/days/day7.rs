@@ -1,7 +1,10 @@
-use std::io::stdin;
-
 use itertools::Itertools;
 
+use crate::{
+    parse::{self, ParseError},
+    solution::{Answer, Solution},
+};
+
 fn char_to_rank(ch: char) -> Option<u8> {
     match ch {
         '2' => Some(2),
@@ -46,7 +49,7 @@ fn rank_hand(hand: [u8; 5]) -> HandRank {
     non_jokers.sort();
     let mut runs = non_jokers
         .into_iter()
-        .group_by(|&r| r)
+        .chunk_by(|&r| r)
         .into_iter()
         .map(|(_, run)| run.count())
         .collect::<Vec<usize>>();
@@ -63,40 +66,47 @@ fn rank_hand(hand: [u8; 5]) -> HandRank {
     }
 }
 
-fn main() {
-    let input: Vec<([u8; 5], u32)> = stdin()
-        .lines()
-        .map(|res| {
-            let line = res.unwrap();
-            let (hand, bid) = line.split_once(' ').unwrap();
-            (
-                hand.chars()
-                    .filter_map(char_to_rank)
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap(),
-                bid.parse().unwrap(),
-            )
-        })
-        .collect();
+fn score(mut input: Vec<([u8; 5], u32)>) -> u32 {
+    input.sort_by_key(|&(hand, _)| (rank_hand(hand), hand));
+    input
+        .into_iter()
+        .enumerate()
+        .map(|(i, (_, bid))| (i + 1) as u32 * bid)
+        .sum::<u32>()
+}
 
-    let score = |mut input: Vec<([u8; 5], u32)>| {
-        input.sort_by_key(|&(hand, _)| (rank_hand(hand), hand));
-        input
-            .into_iter()
-            .enumerate()
-            .map(|(i, (_, bid))| (i + 1) as u32 * bid)
-            .sum::<u32>()
-    };
+pub struct Day7;
 
-    let part1 = score(input.clone());
-    let part2 = score(
+impl Solution for Day7 {
+    type Input = Vec<([u8; 5], u32)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
         input
-            .into_iter()
-            .map(|(hand, bid)| (hand.map(jack_for_joker), bid))
-            .collect(),
-    );
+            .lines()
+            .map(|line| {
+                let (hand, bid) = line
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at(input, line, "expected a hand and a bid"))?;
+                Ok((
+                    parse::chars(input, hand, char_to_rank)?
+                        .try_into()
+                        .map_err(|_| ParseError::at(input, hand, "expected five cards"))?,
+                    parse::number(input, bid)?,
+                ))
+            })
+            .collect()
+    }
 
-    println!("{}", part1);
-    println!("{}", part2);
+    fn part1(&self, input: &Self::Input) -> impl Answer {
+        score(input.clone())
+    }
+
+    fn part2(&self, input: &Self::Input) -> impl Answer {
+        score(
+            input
+                .iter()
+                .map(|&(hand, bid)| (hand.map(jack_for_joker), bid))
+                .collect(),
+        )
+    }
 }
@@ -0,0 +1,112 @@
+use std::collections::{HashMap, HashSet};
+
+use itertools::iterate;
+use regex::Regex;
+
+use crate::{
+    looping_iter::Delooping,
+    parse::{self, ParseError},
+    periodic::PeriodicSet,
+    solution::{Answer, Solution, SolveError},
+};
+
+pub struct Network {
+    directions: Vec<bool>,
+    graph: HashMap<String, [String; 2]>,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let line_re = Regex::new(r"^(\S+) = \((\S+), (\S+)\)$").unwrap();
+        let mut it = input.lines();
+        let first = it
+            .next()
+            .ok_or_else(|| ParseError::eof(input, "expected a line of directions"))?;
+        if first.is_empty() {
+            return Err(ParseError::at(
+                input,
+                first,
+                "expected a line of directions",
+            ));
+        }
+        let directions = parse::chars(input, first, |c| match c {
+            'L' => Some(false),
+            'R' => Some(true),
+            _ => None,
+        })?;
+        it.next()
+            .ok_or_else(|| ParseError::eof(input, "expected a blank line"))?;
+        let nodes = it
+            .map(|line| parse::captures(&line_re, input, line))
+            .collect::<Result<Vec<[&str; 3]>, _>>()?;
+        let defined = nodes
+            .iter()
+            .map(|&[from, _, _]| from)
+            .collect::<HashSet<_>>();
+        if let Some(&to) = nodes
+            .iter()
+            .flat_map(|[_, to1, to2]| [to1, to2])
+            .find(|to| !defined.contains(*to))
+        {
+            return Err(ParseError::at(
+                input,
+                to,
+                format!("node `{}` is never defined", to),
+            ));
+        }
+        let graph = nodes
+            .into_iter()
+            .map(|[from, to1, to2]| (String::from(from), [String::from(to1), String::from(to2)]))
+            .collect();
+        Ok(Network { directions, graph })
+    }
+
+    fn part1(&self, Network { directions, graph }: &Self::Input) -> impl Answer {
+        for node in ["AAA", "ZZZ"] {
+            if !graph.contains_key(node) {
+                return Err(SolveError::new(format!("no node `{}`", node)));
+            }
+        }
+        // After this many steps some node has been reached at the same point of the directions
+        // twice, so every node that is ever reached has been.
+        let limit = graph.len() * directions.len();
+        let mut node = "AAA";
+        let mut count = 0;
+        while node != "ZZZ" {
+            if count == limit {
+                return Err(SolveError::new("`ZZZ` cannot be reached from `AAA`"));
+            }
+            node = &graph[node][directions[count % directions.len()] as usize];
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    fn part2(&self, Network { directions, graph }: &Self::Input) -> impl Answer {
+        let steps = graph
+            .keys()
+            .filter(|&node| node.ends_with('A'))
+            .map(|node| {
+                iterate((0, node), |&(count, node)| {
+                    (
+                        (count + 1) % directions.len(),
+                        &graph[node][directions[count] as usize],
+                    )
+                })
+                .delooping()
+                .occurrences(|(_, node)| node.ends_with('Z'))
+            })
+            .try_fold(PeriodicSet::all(), |a, b| a.intersect(&b));
+        steps
+            .ok_or_else(|| SolveError::new("step count overflows"))
+            .and_then(|steps| {
+                steps
+                    .min()
+                    .ok_or_else(|| SolveError::new("never all on a Z node"))
+            })
+    }
+}
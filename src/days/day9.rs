@@ -0,0 +1,29 @@
+use crate::{
+    parse::{self, ParseError},
+    poly::extrapolate,
+    solution::{Answer, Solution},
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| parse::numbers(input, line))
+            .collect()
+    }
+
+    fn part1(&self, numbers: &Self::Input) -> impl Answer {
+        numbers
+            .iter()
+            .map(|row| extrapolate(row, row.len() as i32))
+            .sum::<i32>()
+    }
+
+    fn part2(&self, numbers: &Self::Input) -> impl Answer {
+        numbers.iter().map(|row| extrapolate(row, -1)).sum::<i32>()
+    }
+}
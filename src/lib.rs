@@ -1,4 +1,13 @@
+pub mod bench;
+pub mod bigint;
+pub mod days;
 pub mod direction;
-pub mod rect;
 pub mod looping_iter;
 pub mod numbers;
+pub mod parse;
+pub mod periodic;
+pub mod poly;
+pub mod rect;
+pub mod search;
+pub mod solution;
+pub mod state_set;
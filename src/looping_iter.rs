@@ -1,181 +1,586 @@
 use std::{
-    cell::RefCell, collections::HashMap, hash::Hash, iter::FusedIterator, mem, ops::Deref, rc::Rc,
+    cell::{OnceCell, RefCell},
+    collections::{hash_map::Entry, HashMap},
+    error::Error,
+    fmt,
+    hash::Hash,
+    iter::FusedIterator,
+    mem,
+    ops::Deref,
+    rc::Rc,
+    sync::{Arc, Mutex, OnceLock},
 };
 
-enum LoopingState<I: Iterator> {
-    Searching {
-        trace: Vec<I::Item>,
-        locations: HashMap<I::Item, usize>,
-        iter: I,
-    },
-    Exhausted {
-        trace: Vec<I::Item>,
-    },
-    Looped {
-        init: Vec<I::Item>,
-        cycle: Vec<I::Item>,
-    },
-}
-
-impl<I: Iterator> LoopingState<I>
-where
-    I::Item: Eq + Hash + Clone,
-{
-    fn get_cycle<'a>(init: &Vec<I::Item>, cycle: &'a Vec<I::Item>, n: usize) -> &'a I::Item {
-        &cycle[(n - init.len()) % cycle.len()]
+use itertools::Itertools;
+
+use crate::periodic::PeriodicSet;
+
+/// Compares two items whose keys are equal.
+type Verify<T> = fn(&T, &T) -> bool;
+
+/// The part of a sequence before it loops, and the loop itself, which is empty if the sequence
+/// ends.
+struct Shape<T> {
+    init: Vec<T>,
+    cycle: Vec<T>,
+}
+
+impl<T> Shape<T> {
+    fn get(&self, n: u128) -> Option<&T> {
+        match n.checked_sub(self.init.len() as u128) {
+            None => Some(&self.init[n as usize]),
+            Some(_) if self.cycle.is_empty() => None,
+            Some(n) => Some(&self.cycle[(n % self.cycle.len() as u128) as usize]),
+        }
     }
+}
 
-    fn get(&mut self, n: usize) -> Option<&I::Item> {
-        match self {
-            Self::Exhausted { trace } => trace.get(n),
-            Self::Looped { init, cycle } => Some(match init.get(n) {
-                Some(value) => value,
-                None => Self::get_cycle(init, cycle, n),
-            }),
-            Self::Searching {
-                trace,
-                locations,
-                iter,
-            } => {
-                while trace.len() <= n {
-                    if let Some(value) = iter.next() {
-                        if let Some(&pos) = locations.get(&value) {
-                            let mut init = mem::take(trace);
-                            let cycle = init.split_off(pos);
-                            *self = Self::Looped { init, cycle };
-                            return match self {
-                                Self::Looped { init, cycle } => {
-                                    Some(Self::get_cycle(init, cycle, n))
-                                }
-                                _ => unreachable!(),
-                            };
-                        } else {
-                            locations.insert(value.clone(), trace.len());
-                            trace.push(value)
+struct Search<I: Iterator, K, F> {
+    trace: Vec<I::Item>,
+    /// The first index of each key.
+    locations: HashMap<K, usize>,
+    /// For each index, the next index with the same key but a different item. Only used when
+    /// verifying.
+    collisions: Vec<Option<usize>>,
+    iter: I,
+    key: F,
+    verify: Option<Verify<I::Item>>,
+}
+
+impl<I: Iterator, K, F> Search<I, K, F>
+where
+    K: Eq + Hash,
+    F: FnMut(&I::Item) -> K,
+{
+    /// Takes one more item, and returns the shape of the sequence once it is known.
+    fn advance(&mut self) -> Option<Shape<I::Item>> {
+        let Some(value) = self.iter.next() else {
+            return Some(Shape {
+                init: mem::take(&mut self.trace),
+                cycle: Vec::new(),
+            });
+        };
+        let index = self.trace.len();
+        match self.locations.entry((self.key)(&value)) {
+            Entry::Occupied(entry) => {
+                let mut pos = *entry.get();
+                if let Some(eq) = self.verify {
+                    while !eq(&self.trace[pos], &value) {
+                        match self.collisions[pos] {
+                            Some(next) => pos = next,
+                            None => {
+                                self.collisions[pos] = Some(index);
+                                self.collisions.push(None);
+                                self.trace.push(value);
+                                return None;
+                            }
                         }
-                    } else {
-                        let trace = mem::take(trace);
-                        *self = Self::Exhausted { trace };
-                        return None;
                     }
                 }
-                match self {
-                    Self::Searching { trace, .. } => Some(&trace[n]),
-                    _ => unreachable!(),
+                let mut init = mem::take(&mut self.trace);
+                let cycle = init.split_off(pos);
+                Some(Shape { init, cycle })
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+                if self.verify.is_some() {
+                    self.collisions.push(None);
                 }
+                self.trace.push(value);
+                None
             }
         }
     }
+}
+
+/// How the state of a `LoopingIter` is shared between its clones: either `Local` to a thread, or
+/// `Shared` between threads.
+pub trait Sharing {
+    type Ptr<T>: Deref<Target = T> + Clone;
+    type Lock<T>;
+    type Once<T>;
+
+    fn ptr<T>(value: T) -> Self::Ptr<T>;
+    fn lock<T>(value: T) -> Self::Lock<T>;
+    fn with_lock<T, R>(lock: &Self::Lock<T>, f: impl FnOnce(&mut T) -> R) -> R;
+    fn once<T>() -> Self::Once<T>;
+    fn get<T>(once: &Self::Once<T>) -> Option<&T>;
+    fn get_or_init<T>(once: &Self::Once<T>, f: impl FnOnce() -> T) -> &T;
+}
+
+pub enum Local {}
+
+impl Sharing for Local {
+    type Ptr<T> = Rc<T>;
+    type Lock<T> = RefCell<T>;
+    type Once<T> = OnceCell<T>;
+
+    fn ptr<T>(value: T) -> Rc<T> {
+        Rc::new(value)
+    }
+
+    fn lock<T>(value: T) -> RefCell<T> {
+        RefCell::new(value)
+    }
+
+    fn with_lock<T, R>(lock: &RefCell<T>, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut lock.borrow_mut())
+    }
+
+    fn once<T>() -> OnceCell<T> {
+        OnceCell::new()
+    }
+
+    fn get<T>(once: &OnceCell<T>) -> Option<&T> {
+        once.get()
+    }
+
+    fn get_or_init<T>(once: &OnceCell<T>, f: impl FnOnce() -> T) -> &T {
+        once.get_or_init(f)
+    }
+}
+
+pub enum Shared {}
+
+impl Sharing for Shared {
+    type Ptr<T> = Arc<T>;
+    type Lock<T> = Mutex<T>;
+    type Once<T> = OnceLock<T>;
+
+    fn ptr<T>(value: T) -> Arc<T> {
+        Arc::new(value)
+    }
+
+    fn lock<T>(value: T) -> Mutex<T> {
+        Mutex::new(value)
+    }
+
+    fn with_lock<T, R>(lock: &Mutex<T>, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut lock.lock().unwrap())
+    }
 
-    fn force_loop(&mut self) -> (&[I::Item], &[I::Item]) {
-        match self {
-            Self::Exhausted { trace } => (trace, &[]),
-            Self::Looped { init, cycle } => (init, cycle),
-            Self::Searching {
-                trace,
-                locations,
-                iter,
-            } => loop {
-                if let Some(value) = iter.next() {
-                    if let Some(&pos) = locations.get(&value) {
-                        let mut init = mem::take(trace);
-                        let cycle = init.split_off(pos);
-                        *self = Self::Looped { init, cycle };
-                        break match self {
-                            Self::Looped { init, cycle } => (init, cycle),
-                            _ => unreachable!(),
-                        };
-                    } else {
-                        locations.insert(value.clone(), trace.len());
-                        trace.push(value)
+    fn once<T>() -> OnceLock<T> {
+        OnceLock::new()
+    }
+
+    fn get<T>(once: &OnceLock<T>) -> Option<&T> {
+        once.get()
+    }
+
+    fn get_or_init<T>(once: &OnceLock<T>, f: impl FnOnce() -> T) -> &T {
+        once.get_or_init(f)
+    }
+}
+
+/// The search for a loop while it is in progress, and its result once it is done.
+struct State<I: Iterator, K, F, P: Sharing> {
+    search: P::Lock<Option<Search<I, K, F>>>,
+    shape: P::Once<Shape<I::Item>>,
+}
+
+impl<I: Iterator, K, F, P: Sharing> State<I, K, F, P>
+where
+    K: Eq + Hash,
+    F: FnMut(&I::Item) -> K,
+{
+    fn finish(&self, search: &mut Option<Search<I, K, F>>, shape: Shape<I::Item>) {
+        P::get_or_init(&self.shape, || shape);
+        *search = None;
+    }
+
+    /// The `n`th item, searching only as far as needed to reach it.
+    fn get(&self, n: u128) -> Option<I::Item>
+    where
+        I::Item: Clone,
+    {
+        if let Some(shape) = P::get(&self.shape) {
+            return shape.get(n).cloned();
+        }
+        P::with_lock(&self.search, |search| loop {
+            match search {
+                None => return P::get(&self.shape).unwrap().get(n).cloned(),
+                Some(active) if n < active.trace.len() as u128 => {
+                    return Some(active.trace[n as usize].clone())
+                }
+                Some(active) => {
+                    if let Some(shape) = active.advance() {
+                        self.finish(search, shape);
+                    }
+                }
+            }
+        })
+    }
+
+    /// The first index whose item satisfies `pred`, searching only as far as needed to find it.
+    fn find(&self, mut pred: impl FnMut(&I::Item) -> bool) -> Option<usize> {
+        if P::get(&self.shape).is_none() {
+            let mut checked = 0;
+            let found = P::with_lock(&self.search, |search| {
+                while let Some(active) = search {
+                    if let Some(i) = active.trace[checked..].iter().position(&mut pred) {
+                        return Some(checked + i);
+                    }
+                    checked = active.trace.len();
+                    if let Some(shape) = active.advance() {
+                        self.finish(search, shape);
                     }
-                } else {
-                    let trace = mem::take(trace);
-                    *self = Self::Exhausted { trace };
-                    break match self {
-                        Self::Exhausted { trace } => (trace, &[]),
-                        _ => unreachable!(),
-                    };
                 }
-            },
+                None
+            });
+            if found.is_some() {
+                return found;
+            }
         }
+        let Shape { init, cycle } = P::get(&self.shape).unwrap();
+        init.iter().chain(cycle).position(pred)
+    }
+
+    fn force(&self) -> &Shape<I::Item> {
+        if let Some(shape) = P::get(&self.shape) {
+            return shape;
+        }
+        P::with_lock(&self.search, |search| {
+            while let Some(active) = search {
+                if let Some(shape) = active.advance() {
+                    self.finish(search, shape);
+                }
+            }
+        });
+        P::get(&self.shape).unwrap()
+    }
+
+    fn size_hint(&self, pos: usize) -> (usize, Option<usize>) {
+        let searched = P::with_lock(&self.search, |search| {
+            search.as_ref().map(|active| active.trace.len())
+        });
+        match (searched, P::get(&self.shape)) {
+            (Some(len), _) => (len.saturating_sub(pos), None),
+            (None, Some(shape)) if shape.cycle.is_empty() => {
+                let len = shape.init.len().saturating_sub(pos);
+                (len, Some(len))
+            }
+            _ => (usize::MAX, None),
+        }
+    }
+}
+
+/// The error of counting the items of a sequence that loops forever, with the lengths of the part
+/// before the loop and of the loop itself.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Looping {
+    pub init: usize,
+    pub cycle: usize,
+}
+
+impl fmt::Display for Looping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "sequence loops with period {} after {} items",
+            self.cycle, self.init
+        )
     }
 }
 
-#[derive(Clone)]
-pub struct LoopingIter<I: Iterator> {
+impl Error for Looping {}
+
+/// An iterator that remembers the items of `I` until one of them repeats, and from then on cycles
+/// through the remembered items instead of querying `I`. Items are compared by the key `F` gives
+/// them, which by default is a clone of the item itself. Clones share the remembered items.
+pub struct LoopingIter<
+    I: Iterator,
+    K = <I as Iterator>::Item,
+    F = fn(&<I as Iterator>::Item) -> K,
+    P: Sharing = Local,
+> {
     pos: usize,
-    state: Rc<RefCell<LoopingState<I>>>,
+    state: P::Ptr<State<I, K, F, P>>,
 }
 
-impl<I: Iterator> LoopingIter<I> {
-    pub fn new(iter: I) -> LoopingIter<I> {
+/// A `LoopingIter` whose clones can be sent to and used from other threads.
+pub type SyncLoopingIter<I, K = <I as Iterator>::Item, F = fn(&<I as Iterator>::Item) -> K> =
+    LoopingIter<I, K, F, Shared>;
+
+impl<I: Iterator, K, F, P: Sharing> Clone for LoopingIter<I, K, F, P> {
+    fn clone(&self) -> Self {
+        LoopingIter {
+            pos: self.pos,
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<I: Iterator> LoopingIter<I>
+where
+    I::Item: Eq + Hash + Clone,
+{
+    pub fn new(iter: I) -> Self {
+        LoopingIter::with_key(iter, I::Item::clone, None)
+    }
+}
+
+impl<I: Iterator> SyncLoopingIter<I>
+where
+    I::Item: Eq + Hash + Clone,
+{
+    pub fn new_sync(iter: I) -> Self {
+        LoopingIter::with_key(iter, I::Item::clone, None)
+    }
+}
+
+impl<I: Iterator, K, F, P: Sharing> LoopingIter<I, K, F, P>
+where
+    K: Eq + Hash,
+    F: FnMut(&I::Item) -> K,
+{
+    fn with_key(iter: I, key: F, verify: Option<Verify<I::Item>>) -> Self {
         LoopingIter {
             pos: 0,
-            state: Rc::new(RefCell::new(LoopingState::Searching {
-                trace: Vec::new(),
-                locations: HashMap::new(),
-                iter,
-            })),
+            state: P::ptr(State {
+                search: P::lock(Some(Search {
+                    trace: Vec::new(),
+                    locations: HashMap::new(),
+                    collisions: Vec::new(),
+                    iter,
+                    key,
+                    verify,
+                })),
+                shape: P::once(),
+            }),
         }
     }
 
     pub fn loop_structure(&self) -> (Vec<I::Item>, Vec<I::Item>)
     where
-        I::Item: Eq + Hash + Clone,
+        I::Item: Clone,
+    {
+        let shape = self.state.force();
+        (shape.init.clone(), shape.cycle.clone())
+    }
+
+    /// The number of remaining items, or the shape of the loop if there are infinitely many.
+    pub fn try_count(&self) -> Result<usize, Looping> {
+        match self.state.force() {
+            Shape { init, cycle } if cycle.is_empty() => Ok(init.len().saturating_sub(self.pos)),
+            Shape { init, cycle } => Err(Looping {
+                init: init.len(),
+                cycle: cycle.len(),
+            }),
+        }
+    }
+
+    /// The `n`th item of the whole sequence, regardless of how far this iterator has advanced.
+    /// Only searches for the loop as far as `n`.
+    pub fn at(&self, n: u128) -> Option<I::Item>
+    where
+        I::Item: Clone,
     {
-        let mut state = self.state.borrow_mut();
-        let (init, cycle) = state.force_loop();
-        (init.to_vec(), cycle.to_vec())
+        self.state.get(n)
+    }
+
+    /// The number of items before the loop, or of all items if the sequence ends.
+    pub fn prefix_len(&self) -> usize {
+        self.state.force().init.len()
+    }
+
+    /// The length of the loop, or `None` if the sequence ends.
+    pub fn period(&self) -> Option<usize> {
+        let cycle = &self.state.force().cycle;
+        (!cycle.is_empty()).then_some(cycle.len())
+    }
+
+    /// The first index at which `item` appears. Only searches for the loop as far as that index,
+    /// or to the end if `item` never appears.
+    pub fn position_of(&self, item: &I::Item) -> Option<usize>
+    where
+        I::Item: PartialEq,
+    {
+        self.state.find(|value| value == item)
+    }
+
+    /// All indices of the sequence whose items satisfy `pred`.
+    pub fn occurrences(&self, mut pred: impl FnMut(&I::Item) -> bool) -> PeriodicSet {
+        let Shape { init, cycle } = self.state.force();
+        let init_len = init.len() as u128;
+        let period = cycle.len().max(1) as u128;
+        let init = init
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| pred(item).then_some(i as u128))
+            .collect_vec();
+        let residues = cycle
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| pred(item).then_some((init_len + i as u128) % period))
+            .collect_vec();
+        PeriodicSet::new(init_len, init, period, residues)
     }
 }
 
-impl<I: Iterator> Iterator for LoopingIter<I>
+impl<I: Iterator, K, F, P: Sharing> Iterator for LoopingIter<I, K, F, P>
 where
-    I::Item: Eq + Hash + Clone,
+    I::Item: Clone,
+    K: Eq + Hash,
+    F: FnMut(&I::Item) -> K,
 {
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let result = self.state.borrow_mut().get(self.pos).cloned();
+        let result = self.state.get(self.pos as u128);
         self.pos += 1;
         result
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        match self.state.borrow().deref() {
-            LoopingState::Searching { trace, .. } => (trace.len() - self.pos, None),
-            LoopingState::Exhausted { trace } => {
-                (trace.len() - self.pos, Some(trace.len() - self.pos))
-            }
-            LoopingState::Looped { .. } => (usize::MAX, None),
-        }
+        self.state.size_hint(self.pos)
     }
 
     fn count(self) -> usize {
-        match self.state.borrow_mut().force_loop() {
-            (init, []) => init.len(),
-            _ => panic!("count of a looping iterator"),
-        }
+        self.try_count().expect("count of a looping iterator")
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        let result = self.state.borrow_mut().get(self.pos + n).cloned();
+        let result = self.state.get((self.pos + n) as u128);
         self.pos += n;
         self.pos += 1;
         result
     }
 }
 
-impl<I: Iterator> FusedIterator for LoopingIter<I> where I::Item: Eq + Hash + Clone {}
+impl<I: Iterator, K, F, P: Sharing> FusedIterator for LoopingIter<I, K, F, P>
+where
+    I::Item: Clone,
+    K: Eq + Hash,
+    F: FnMut(&I::Item) -> K,
+{
+}
 
 pub trait Delooping: Iterator {
     fn delooping(self) -> LoopingIter<Self>
     where
         Self: Sized,
+        Self::Item: Eq + Hash + Clone,
     {
         LoopingIter::new(self)
     }
+
+    /// Like `delooping`, but detects repeats by comparing `key(item)` instead of whole items.
+    /// Two items with the same key are assumed to be equal.
+    fn delooping_by_key<K, F>(self, key: F) -> LoopingIter<Self, K, F>
+    where
+        Self: Sized,
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        LoopingIter::with_key(self, key, None)
+    }
+
+    /// Like `delooping_by_key`, but when two items have the same key also compares the items
+    /// themselves, so that items which only share a key are not taken for a repeat.
+    fn delooping_by_key_verified<K, F>(self, key: F) -> LoopingIter<Self, K, F>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        LoopingIter::with_key(self, key, Some(Self::Item::eq))
+    }
+
+    /// Like `delooping`, but the result can be shared between threads.
+    fn delooping_sync(self) -> SyncLoopingIter<Self>
+    where
+        Self: Sized,
+        Self::Item: Eq + Hash + Clone,
+    {
+        SyncLoopingIter::new_sync(self)
+    }
+
+    /// Like `delooping_by_key`, but the result can be shared between threads.
+    fn delooping_by_key_sync<K, F>(self, key: F) -> SyncLoopingIter<Self, K, F>
+    where
+        Self: Sized,
+        K: Eq + Hash,
+        F: FnMut(&Self::Item) -> K,
+    {
+        LoopingIter::with_key(self, key, None)
+    }
 }
 
 impl<I: Iterator> Delooping for I {}
+
+/// Cycle detection on the sequence `start`, `step(start)`, `step(step(start))`, ... that keeps
+/// only a couple of states in memory at a time, at the cost of calling `step` about three times
+/// as often as `LoopingIter` would.
+pub struct BrentCycle<S, F> {
+    start: S,
+    step: F,
+    mu: usize,
+    lambda: usize,
+}
+
+impl<S, F> BrentCycle<S, F>
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    /// Finds the cycle using Brent's algorithm. Does not terminate if the sequence never repeats.
+    pub fn new(start: S, mut step: F) -> Self {
+        let mut power = 1;
+        let mut lambda = 1;
+        let mut tortoise = start.clone();
+        let mut hare = step(&start);
+        while tortoise != hare {
+            if power == lambda {
+                tortoise = hare.clone();
+                power *= 2;
+                lambda = 0;
+            }
+            hare = step(&hare);
+            lambda += 1;
+        }
+
+        let mut tortoise = start.clone();
+        let mut hare = start.clone();
+        for _ in 0..lambda {
+            hare = step(&hare);
+        }
+        let mut mu = 0;
+        while tortoise != hare {
+            tortoise = step(&tortoise);
+            hare = step(&hare);
+            mu += 1;
+        }
+
+        BrentCycle {
+            start,
+            step,
+            mu,
+            lambda,
+        }
+    }
+
+    /// The index of the first state that is part of the cycle.
+    pub fn mu(&self) -> usize {
+        self.mu
+    }
+
+    /// The length of the cycle.
+    pub fn lambda(&self) -> usize {
+        self.lambda
+    }
+
+    /// The `n`th state of the sequence, computed in at most `mu + lambda` steps.
+    pub fn nth(&mut self, n: usize) -> S {
+        let n = if n < self.mu {
+            n
+        } else {
+            self.mu + (n - self.mu) % self.lambda
+        };
+        let mut state = self.start.clone();
+        for _ in 0..n {
+            state = (self.step)(&state);
+        }
+        state
+    }
+}
@@ -1,6 +1,7 @@
 use std::{
-    mem,
-    ops::{Add, AddAssign, Div, Mul, Rem, Sub, SubAssign},
+    cmp::Ordering,
+    fmt, mem,
+    ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign},
 };
 
 pub fn gcd<T>(mut a: T, mut b: T) -> T
@@ -97,3 +98,492 @@ where
         (rem_b * gcd.k_a % b * gcd.f_a + (a.clone() - rem_a) * gcd.k_b % a * gcd.f_b) % m
     }
 }
+
+/// Like `Mul`, but returns `None` on overflow.
+pub trait CheckedMul: Sized {
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+}
+
+/// Like `Add`, but returns `None` on overflow.
+pub trait CheckedAdd: Sized {
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+}
+
+/// Like `Sub`, but returns `None` on overflow.
+pub trait CheckedSub: Sized {
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+}
+
+/// Like `Div`, but returns `None` on overflow or division by zero.
+pub trait CheckedDiv: Sized {
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedMul for $t {
+                fn checked_mul(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *other)
+                }
+            }
+
+            impl CheckedAdd for $t {
+                fn checked_add(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *other)
+                }
+            }
+
+            impl CheckedSub for $t {
+                fn checked_sub(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_sub(*self, *other)
+                }
+            }
+
+            impl CheckedDiv for $t {
+                fn checked_div(&self, other: &Self) -> Option<Self> {
+                    <$t>::checked_div(*self, *other)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Like `bezout_identity`, but returns `None` instead of overflowing.
+pub fn checked_bezout<T>(mut a: T, mut b: T) -> Option<ExtendedGCD<T>>
+where
+    T: PartialEq + From<bool> + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv,
+{
+    let (mut k_a, mut k_b, mut f_b, mut f_a) =
+        (T::from(true), T::from(false), T::from(false), T::from(true));
+    let mut sign = false;
+    while b.ne(&T::from(false)) {
+        let q = a.checked_div(&b)?;
+        k_a = k_a.checked_add(&f_b.checked_mul(&q)?)?;
+        mem::swap(&mut k_a, &mut f_b);
+        k_b = k_b.checked_add(&f_a.checked_mul(&q)?)?;
+        mem::swap(&mut k_b, &mut f_a);
+        a = a.checked_sub(&b.checked_mul(&q)?)?;
+        mem::swap(&mut a, &mut b);
+        sign = !sign;
+    }
+    Some(ExtendedGCD {
+        k_a,
+        k_b,
+        f_a,
+        f_b,
+        gcd: a,
+        sign,
+    })
+}
+
+/// Like `lcm`, but returns `None` instead of overflowing.
+pub fn checked_lcm<T>(a: T, b: T) -> Option<T>
+where
+    T: PartialEq + Clone + From<bool> + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv,
+{
+    checked_bezout(a, b.clone())?.f_a.checked_mul(&b)
+}
+
+/// The integer types `Mod` can be used with.
+pub trait ModInt:
+    Copy
+    + PartialOrd
+    + From<bool>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+}
+
+impl<T> ModInt for T where
+    T: Copy
+        + PartialOrd
+        + From<bool>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+        + AddAssign
+        + SubAssign
+{
+}
+
+/// An integer modulo `modulus`, always kept in `0..modulus`. Combining values with different
+/// moduli panics. Multiplication overflows unless the square of the modulus fits in `T`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Mod<T> {
+    value: T,
+    modulus: T,
+}
+
+impl<T: ModInt> Mod<T> {
+    /// Panics unless `modulus` is positive.
+    pub fn new(value: T, modulus: T) -> Self {
+        let zero = T::from(false);
+        assert!(modulus > zero, "modulus must be positive");
+        let value = value % modulus;
+        Mod {
+            value: if value < zero { value + modulus } else { value },
+            modulus,
+        }
+    }
+
+    pub fn value(self) -> T {
+        self.value
+    }
+
+    pub fn modulus(self) -> T {
+        self.modulus
+    }
+
+    pub fn pow(self, mut exp: u128) -> Self {
+        let mut base = self;
+        let mut result = Mod::new(T::from(true), self.modulus);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// The multiplicative inverse, if the value is coprime to the modulus.
+    pub fn inverse(self) -> Option<Self> {
+        let e = bezout_identity(self.value, self.modulus);
+        if e.gcd != T::from(true) {
+            return None;
+        }
+        let inverse = Mod::new(e.k_a, self.modulus);
+        Some(if e.sign { -inverse } else { inverse })
+    }
+
+    fn check(self, other: Self) -> T {
+        assert!(self.modulus == other.modulus, "mismatched moduli");
+        self.modulus
+    }
+}
+
+impl<T: ModInt> Add for Mod<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let modulus = self.check(other);
+        let value = if self.value >= modulus - other.value {
+            self.value - (modulus - other.value)
+        } else {
+            self.value + other.value
+        };
+        Mod { value, modulus }
+    }
+}
+
+impl<T: ModInt> Sub for Mod<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<T: ModInt> Neg for Mod<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let value = if self.value == T::from(false) {
+            self.value
+        } else {
+            self.modulus - self.value
+        };
+        Mod {
+            value,
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<T: ModInt> Mul for Mod<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let modulus = self.check(other);
+        Mod {
+            value: self.value * other.value % modulus,
+            modulus,
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Mod<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+/// `a * b` by doubling, so that no intermediate value exceeds the modulus. Slower than `Mul`, but
+/// cannot overflow. Panics if `b` is negative.
+fn mul_doubling<T: ModInt>(mut a: Mod<T>, mut b: T) -> Mod<T> {
+    let (zero, one) = (T::from(false), T::from(true));
+    let two = one + one;
+    assert!(b >= zero, "negative factor");
+    let mut result = Mod::new(zero, a.modulus);
+    while b > zero {
+        if b % two == one {
+            result = result + a;
+        }
+        a = a + a;
+        b = b / two;
+    }
+    result
+}
+
+/// Combines `n = r1 (mod m1)` and `n = r2 (mod m2)` into a single congruence, using `bezout`,
+/// `lcm`, `mul` and `add` for the steps that may overflow.
+fn combine<T: ModInt>(
+    (r1, m1): (T, T),
+    (r2, m2): (T, T),
+    bezout: impl Fn(T, T) -> Option<ExtendedGCD<T>>,
+    lcm: impl Fn(T, T) -> Option<T>,
+    mul: impl Fn(T, T) -> Option<T>,
+    add: impl Fn(T, T) -> Option<T>,
+) -> Option<(T, T)> {
+    let (r1, r2) = (Mod::new(r1, m1).value(), Mod::new(r2, m2).value());
+    let e = bezout(m1, m2)?;
+    if r1 % e.gcd != r2 % e.gcd {
+        return None;
+    }
+    // n = r1 + m1 * t, where t = (r2 - r1) / gcd * (m1 / gcd)^-1 (mod m2 / gcd)
+    let diff = Mod::new(r2 / e.gcd, e.f_b) - Mod::new(r1 / e.gcd, e.f_b);
+    let inverse = Mod::new(e.f_a, e.f_b).inverse().unwrap();
+    let t = mul_doubling(diff, inverse.value()).value();
+    Some((add(r1, mul(m1, t)?)?, lcm(m1, m2)?))
+}
+
+/// Solves the system of congruences `n = rem (mod modulus)` for every `(rem, modulus)` in
+/// `congruences`, which need not be coprime. Returns the smallest non-negative solution and the
+/// least common multiple of the moduli, or `None` if the congruences contradict each other.
+/// Panics if a modulus is not positive.
+pub fn crt<T: ModInt>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    congruences
+        .iter()
+        .try_fold((T::from(false), T::from(true)), |acc, &c| {
+            combine(
+                acc,
+                c,
+                |a, b| Some(bezout_identity(a, b)),
+                |a, b| Some(lcm(a, b)),
+                |a, b| Some(a * b),
+                |a, b| Some(a + b),
+            )
+        })
+}
+
+/// Like `crt`, but also returns `None` if the least common multiple of the moduli overflows.
+pub fn checked_crt<T>(congruences: &[(T, T)]) -> Option<(T, T)>
+where
+    T: ModInt + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv,
+{
+    congruences
+        .iter()
+        .try_fold((T::from(false), T::from(true)), |acc, &c| {
+            combine(
+                acc,
+                c,
+                checked_bezout,
+                checked_lcm,
+                |a, b| a.checked_mul(&b),
+                |a, b| a.checked_add(&b),
+            )
+        })
+}
+
+/// The largest integer whose square is at most `n`. Panics if `n` is negative.
+pub fn isqrt<T>(n: T) -> T
+where
+    T: Copy + PartialOrd + From<bool> + Add<Output = T> + Div<Output = T>,
+{
+    let (zero, one) = (T::from(false), T::from(true));
+    let two = one + one;
+    assert!(n >= zero, "square root of a negative number");
+    if n < two {
+        return n;
+    }
+    // Newton's method, starting above the root so that the estimate only decreases.
+    let mut x = n / two + one;
+    loop {
+        let y = (x + n / x) / two;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// The integer types `Ratio` can be used with.
+pub trait Integer:
+    Clone
+    + Ord
+    + From<bool>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + SubAssign
+{
+}
+
+impl<T> Integer for T where
+    T: Clone
+        + Ord
+        + From<bool>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+        + SubAssign
+{
+}
+
+/// A fraction in lowest terms with a positive denominator. Dividing by zero panics.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Ratio<T> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: Integer> Ratio<T> {
+    /// Panics if `denom` is zero.
+    pub fn new(numer: T, denom: T) -> Self {
+        let zero = T::from(false);
+        assert!(denom != zero, "zero denominator");
+        let mut g = gcd(numer.clone(), denom.clone());
+        if (g < zero) != (denom < zero) {
+            g = zero - g;
+        }
+        Ratio {
+            numer: numer / g.clone(),
+            denom: denom / g,
+        }
+    }
+
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    /// The largest integer not above the fraction.
+    pub fn floor(&self) -> T {
+        let q = self.numer.clone() / self.denom.clone();
+        if self.numer < T::from(false) && self.numer.clone() % self.denom.clone() != T::from(false)
+        {
+            q - T::from(true)
+        } else {
+            q
+        }
+    }
+
+    /// The smallest integer not below the fraction.
+    pub fn ceil(&self) -> T {
+        let q = self.numer.clone() / self.denom.clone();
+        if self.numer > T::from(false) && self.numer.clone() % self.denom.clone() != T::from(false)
+        {
+            q + T::from(true)
+        } else {
+            q
+        }
+    }
+}
+
+impl<T: Integer> From<T> for Ratio<T> {
+    fn from(value: T) -> Self {
+        Ratio {
+            numer: value,
+            denom: T::from(true),
+        }
+    }
+}
+
+impl<T: Integer> Add for Ratio<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Ratio::new(
+            self.numer * other.denom.clone() + other.numer * self.denom.clone(),
+            self.denom * other.denom,
+        )
+    }
+}
+
+impl<T: Integer> Sub for Ratio<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Ratio::new(
+            self.numer * other.denom.clone() - other.numer * self.denom.clone(),
+            self.denom * other.denom,
+        )
+    }
+}
+
+impl<T: Integer> Neg for Ratio<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Ratio {
+            numer: T::from(false) - self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl<T: Integer> Mul for Ratio<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Ratio::new(self.numer * other.numer, self.denom * other.denom)
+    }
+}
+
+impl<T: Integer> Div for Ratio<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Ratio::new(self.numer * other.denom, self.denom * other.numer)
+    }
+}
+
+impl<T: Integer> Ord for Ratio<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer.clone() * other.denom.clone()).cmp(&(other.numer.clone() * self.denom.clone()))
+    }
+}
+
+impl<T: Integer> PartialOrd for Ratio<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer + fmt::Display> fmt::Display for Ratio<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denom == T::from(true) {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::{error::Error, fmt, str::FromStr};
+
+use regex::Regex;
+
+/// An error in puzzle input, located by 1-based line and column (in characters), together with
+/// the text of the offending line.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    snippet: String,
+    message: String,
+}
+
+impl ParseError {
+    /// Error pointing at the start of `span`, which must be a subslice of `input`. A `span` that
+    /// lies outside of `input` is reported at the end of input.
+    pub fn at<M: Into<String>>(input: &str, span: &str, message: M) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= input.len())
+            .unwrap_or(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        ParseError {
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: String::from(input[line_start..line_end].trim_end_matches('\r')),
+            message: message.into(),
+        }
+    }
+
+    /// Error pointing just past the last character of `input`, for inputs that are cut short.
+    pub fn eof<M: Into<String>>(input: &str, message: M) -> Self {
+        Self::at(input, &input[input.len()..], message)
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "    {}", self.snippet)?;
+        write!(f, "    {:>1$}", "^", self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `span` (a subslice of `input`) with `FromStr`.
+pub fn number<T: FromStr>(input: &str, span: &str) -> Result<T, ParseError>
+where
+    T::Err: fmt::Display,
+{
+    span.parse()
+        .map_err(|err| ParseError::at(input, span, format!("invalid number `{}`: {}", span, err)))
+}
+
+/// Parses every whitespace-separated word of `span` (a subslice of `input`) with `FromStr`.
+pub fn numbers<T: FromStr>(input: &str, span: &str) -> Result<Vec<T>, ParseError>
+where
+    T::Err: fmt::Display,
+{
+    span.split_ascii_whitespace()
+        .map(|word| number(input, word))
+        .collect()
+}
+
+/// Matches `re` against `span` (a subslice of `input`) and returns its capture groups.
+pub fn captures<'h, const N: usize>(
+    re: &Regex,
+    input: &str,
+    span: &'h str,
+) -> Result<[&'h str; N], ParseError> {
+    match re.captures(span) {
+        Some(captures) => Ok(captures.extract().1),
+        None => Err(ParseError::at(
+            input,
+            span,
+            format!("expected a match for `{}`", re.as_str()),
+        )),
+    }
+}
+
+/// Maps every character of `span` (a subslice of `input`) through `f`, failing at the first
+/// character that `f` rejects.
+pub fn chars<T, F: FnMut(char) -> Option<T>>(
+    input: &str,
+    span: &str,
+    mut f: F,
+) -> Result<Vec<T>, ParseError> {
+    span.char_indices()
+        .map(|(i, c)| {
+            f(c).ok_or_else(|| {
+                ParseError::at(input, &span[i..], format!("unexpected character `{}`", c))
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,130 @@
+use itertools::Itertools;
+
+use crate::numbers::{checked_crt, checked_lcm, gcd};
+
+/// A set of natural numbers that is periodic from some point on: below `init_len` it contains
+/// exactly the numbers in `init`, and from `init_len` on every `n` with `n % period` in
+/// `residues`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PeriodicSet {
+    init_len: u128,
+    init: Vec<u128>,
+    period: u128,
+    residues: Vec<u128>,
+}
+
+impl PeriodicSet {
+    /// Panics if `period` is 0 or some number in `init` is not below `init_len`.
+    pub fn new(
+        init_len: u128,
+        init: impl IntoIterator<Item = u128>,
+        period: u128,
+        residues: impl IntoIterator<Item = u128>,
+    ) -> Self {
+        assert!(period > 0, "period must be positive");
+        let init = init.into_iter().sorted().dedup().collect_vec();
+        assert!(init.iter().all(|&n| n < init_len));
+        let residues = residues
+            .into_iter()
+            .map(|r| r % period)
+            .sorted()
+            .dedup()
+            .collect();
+        PeriodicSet {
+            init_len,
+            init,
+            period,
+            residues,
+        }
+    }
+
+    pub fn empty() -> Self {
+        PeriodicSet::new(0, [], 1, [])
+    }
+
+    pub fn all() -> Self {
+        PeriodicSet::new(0, [], 1, [0])
+    }
+
+    pub fn init_len(&self) -> u128 {
+        self.init_len
+    }
+
+    pub fn period(&self) -> u128 {
+        self.period
+    }
+
+    pub fn contains(&self, n: u128) -> bool {
+        if n < self.init_len {
+            self.init.binary_search(&n).is_ok()
+        } else {
+            self.residues.binary_search(&(n % self.period)).is_ok()
+        }
+    }
+
+    pub fn min(&self) -> Option<u128> {
+        self.iter().next()
+    }
+
+    /// The numbers in the set in increasing order. Infinite unless the set is finite.
+    pub fn iter(&self) -> impl Iterator<Item = u128> + '_ {
+        let start = self.init_len - self.init_len % self.period;
+        let blocks = if self.residues.is_empty() {
+            0
+        } else {
+            (u128::MAX - start) / self.period
+        };
+        self.init.iter().copied().chain(
+            (0..blocks)
+                .flat_map(move |k| {
+                    self.residues
+                        .iter()
+                        .map(move |&r| start + k * self.period + r)
+                })
+                .filter(move |&n| n >= self.init_len),
+        )
+    }
+
+    fn below(&self, len: u128) -> impl Iterator<Item = u128> + '_ {
+        self.iter().take_while(move |&n| n < len)
+    }
+
+    /// The intersection of two sets, or `None` if its period or the arithmetic to find its
+    /// residues overflows.
+    pub fn intersect(&self, other: &PeriodicSet) -> Option<PeriodicSet> {
+        let init_len = self.init_len.max(other.init_len);
+        let period = checked_lcm(self.period, other.period)?;
+        let common = gcd(self.period, other.period);
+        let residues = self
+            .residues
+            .iter()
+            .cartesian_product(&other.residues)
+            .filter(|&(&a, &b)| a % common == b % common)
+            .map(|(&a, &b)| Some(checked_crt(&[(a, self.period), (b, other.period)])?.0))
+            .collect::<Option<Vec<_>>>()?;
+        Some(PeriodicSet::new(
+            init_len,
+            self.below(init_len).filter(|&n| other.contains(n)),
+            period,
+            residues,
+        ))
+    }
+
+    /// The union of two sets, or `None` if its period overflows. Its residues are listed
+    /// explicitly, so there may be as many as the least common multiple of the periods.
+    pub fn union(&self, other: &PeriodicSet) -> Option<PeriodicSet> {
+        let init_len = self.init_len.max(other.init_len);
+        let period = checked_lcm(self.period, other.period)?;
+        let residues = |set: &PeriodicSet| {
+            (0..period / set.period)
+                .flat_map(|k| set.residues.iter().map(move |&r| k * set.period + r))
+                .collect_vec()
+        };
+        Some(PeriodicSet::new(
+            init_len,
+            self.below(init_len).chain(other.below(init_len)),
+            period,
+            residues(self).into_iter().chain(residues(other)),
+        ))
+    }
+}
@@ -0,0 +1,104 @@
+use crate::numbers::{Integer, Ratio};
+
+/// The leading entries `seq[0]`, `Δseq[0]`, `Δ²seq[0]`, ... of the forward difference table of
+/// `seq`, which are the coefficients of its Newton series. Stops at the last row that is not all
+/// zero.
+pub fn differences<T: Integer>(seq: &[T]) -> Vec<T> {
+    let zero = T::from(false);
+    let mut row = seq.to_vec();
+    let mut result = Vec::new();
+    while row.iter().any(|x| *x != zero) {
+        result.push(row[0].clone());
+        row = row
+            .windows(2)
+            .map(|w| w[1].clone() - w[0].clone())
+            .collect();
+    }
+    result
+}
+
+/// The degree of the polynomial that `seq` follows, if its difference table reaches a row of
+/// zeros, so that it has at least one more point than needed to determine the polynomial. An
+/// all-zero sequence counts as degree 0.
+pub fn degree<T: Integer>(seq: &[T]) -> Option<usize> {
+    let degree = differences(seq).len().max(1) - 1;
+    (degree + 2 <= seq.len()).then_some(degree)
+}
+
+/// The sum of `C(k, j) * diffs[j]`, where `C` is the binomial coefficient generalized to negative
+/// `k`.
+fn newton_series<T: Integer>(diffs: &[T], k: T) -> T {
+    let one = T::from(true);
+    let mut binomial = one.clone();
+    let mut j = T::from(false);
+    let mut total = T::from(false);
+    for d in diffs {
+        total = total + binomial.clone() * d.clone();
+        binomial = binomial * (k.clone() - j.clone());
+        j = j + one.clone();
+        binomial = binomial / j.clone();
+    }
+    total
+}
+
+/// The value at index `k` of the lowest-degree polynomial through `seq[0]`, `seq[1]`, ...
+/// `k` may lie outside of `seq`, including below 0. Expands around whichever end of `seq` is
+/// closer to `k`, so extrapolating one step in either direction only adds up differences.
+pub fn extrapolate<T: Integer>(seq: &[T], k: T) -> T {
+    let two = T::from(true) + T::from(true);
+    let mut last = T::from(false);
+    for _ in 1..seq.len() {
+        last = last + T::from(true);
+    }
+    if k.clone() * two >= last {
+        let reversed = seq.iter().rev().cloned().collect::<Vec<_>>();
+        newton_series(&differences(&reversed), last - k)
+    } else {
+        newton_series(&differences(seq), k)
+    }
+}
+
+/// The value at `x` of the lowest-degree polynomial through `points`, by Lagrange's formula.
+/// Panics if two points share an `x` coordinate.
+pub fn lagrange<T: Integer>(points: &[(T, T)], x: T) -> Ratio<T> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, (xi, yi))| {
+            points.iter().enumerate().filter(|&(j, _)| j != i).fold(
+                Ratio::from(yi.clone()),
+                |acc, (_, (xj, _))| {
+                    acc * Ratio::new(x.clone() - xj.clone(), xi.clone() - xj.clone())
+                },
+            )
+        })
+        .fold(Ratio::from(T::from(false)), |acc, term| acc + term)
+}
+
+/// The coefficients, constant term first, of the lowest-degree polynomial through `points`.
+/// Panics if two points share an `x` coordinate.
+pub fn interpolate<T: Integer>(points: &[(T, T)]) -> Vec<Ratio<T>> {
+    let zero = Ratio::from(T::from(false));
+    let mut coefficients = vec![zero.clone(); points.len()];
+    for (i, (xi, yi)) in points.iter().enumerate() {
+        // The product of `(X - xj) / (xi - xj)` over all other points, constant term first.
+        let mut basis = vec![Ratio::from(T::from(true))];
+        for (_, (xj, _)) in points.iter().enumerate().filter(|&(j, _)| j != i) {
+            let scale = Ratio::from(xi.clone() - xj.clone());
+            let mut next = vec![zero.clone(); basis.len() + 1];
+            for (n, c) in basis.into_iter().enumerate() {
+                let c = c / scale.clone();
+                next[n + 1] = next[n + 1].clone() + c.clone();
+                next[n] = next[n].clone() - c * Ratio::from(xj.clone());
+            }
+            basis = next;
+        }
+        for (coefficient, c) in coefficients.iter_mut().zip(basis) {
+            *coefficient = coefficient.clone() + c * Ratio::from(yi.clone());
+        }
+    }
+    while coefficients.len() > 1 && coefficients.last() == Some(&zero) {
+        coefficients.pop();
+    }
+    coefficients
+}
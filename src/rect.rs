@@ -1,28 +1,83 @@
 use std::{
-    iter::FusedIterator,
+    convert::Infallible,
+    fmt::{self, Debug, Display},
+    iter::{FusedIterator, StepBy},
+    mem,
     ops::{Index, IndexMut},
     slice, vec,
 };
 
+use strum::IntoEnumIterator;
+
+use crate::{
+    direction::{Direction4, Direction8},
+    parse::ParseError,
+};
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Rect<T> {
     width: usize,
-    row_major: Vec<Vec<T>>, // invariant: all rows have len() equal to width
+    height: usize,
+    cells: Vec<T>, // invariant: cells.len() == width * height, row after row
 }
 
 impl<T> Rect<T> {
     pub fn new_wide(width: usize) -> Self {
         Rect {
             width,
-            row_major: Vec::new(),
+            height: 0,
+            cells: Vec::new(),
         }
     }
 
     pub fn new_tall(height: usize) -> Self {
         Rect {
             width: 0,
-            row_major: (0..height).map(|_| Vec::new()).collect(),
+            height,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Parses a grid with one row per line of `input`, mapping every character through `f`.
+    pub fn parse_grid<E: Display, F: Fn(char) -> Result<T, E>>(
+        input: &str,
+        f: F,
+    ) -> Result<Self, ParseError> {
+        Self::parse_grid_in(input, input, f)
+    }
+
+    /// Like `parse_grid`, for a grid occupying `span`, a subslice of `input`. Errors are located
+    /// in `input`.
+    pub fn parse_grid_in<E: Display, F: Fn(char) -> Result<T, E>>(
+        input: &str,
+        span: &str,
+        f: F,
+    ) -> Result<Self, ParseError> {
+        let mut rect: Option<Self> = None;
+        for line in span.lines() {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| {
+                    f(c).map_err(|err| {
+                        ParseError::at(
+                            input,
+                            &line[i..],
+                            format!("unexpected character `{}`: {}", c, err),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let rect = rect.get_or_insert_with(|| Rect::new_wide(row.len()));
+            if row.len() != rect.width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("row has width {}, expected {}", row.len(), rect.width),
+                ));
+            }
+            rect.push_row(row);
         }
+        Ok(rect.unwrap_or_else(|| Rect::new_wide(0)))
     }
 
     pub fn width(&self) -> usize {
@@ -30,22 +85,30 @@ impl<T> Rect<T> {
     }
 
     pub fn height(&self) -> usize {
-        self.row_major.len()
+        self.height
     }
 
     pub fn push_row(&mut self, row: Vec<T>) {
         assert_eq!(row.len(), self.width);
-        self.row_major.push(row);
+        self.cells.extend(row);
+        self.height += 1;
     }
 
     pub fn pop_row(&mut self) -> Option<Vec<T>> {
-        self.row_major.pop()
+        if self.height == 0 {
+            return None;
+        }
+        self.height -= 1;
+        Some(self.cells.split_off(self.width * self.height))
     }
 
     pub fn push_col(&mut self, col: Vec<T>) {
-        assert_eq!(col.len(), self.row_major.len());
-        for (row, elem) in self.row_major.iter_mut().zip(col) {
-            row.push(elem)
+        assert_eq!(col.len(), self.height);
+        let mut old = mem::take(&mut self.cells).into_iter();
+        self.cells.reserve_exact((self.width + 1) * self.height);
+        for elem in col {
+            self.cells.extend(old.by_ref().take(self.width));
+            self.cells.push(elem);
         }
         self.width += 1;
     }
@@ -54,48 +117,307 @@ impl<T> Rect<T> {
         if self.width == 0 {
             return None;
         }
+        let mut col = Vec::with_capacity(self.height);
+        let mut old = mem::take(&mut self.cells).into_iter();
         self.width -= 1;
-        let mut col = Vec::with_capacity(self.row_major.len());
-        for row in self.row_major.iter_mut() {
-            col.push(row.pop().unwrap())
+        self.cells.reserve_exact(self.width * self.height);
+        for _ in 0..self.height {
+            self.cells.extend(old.by_ref().take(self.width));
+            col.push(old.next().unwrap());
         }
         Some(col)
     }
 
-    pub fn iter(&self) -> slice::Iter<'_, Vec<T>> {
-        self.row_major.iter()
+    pub fn as_slice(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.height);
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn col(&self, x: usize) -> StepBy<slice::Iter<'_, T>> {
+        assert!(x < self.width);
+        self.cells
+            .get(x..)
+            .unwrap_or(&[])
+            .iter()
+            .step_by(self.width)
+    }
+
+    pub fn col_mut(&mut self, x: usize) -> StepBy<slice::IterMut<'_, T>> {
+        assert!(x < self.width);
+        self.cells
+            .get_mut(x..)
+            .unwrap_or(&mut [])
+            .iter_mut()
+            .step_by(self.width)
+    }
+
+    pub fn iter(&self) -> Rows<'_, T> {
+        Rows {
+            width: self.width,
+            remaining: self.height,
+            cells: &self.cells,
+        }
     }
 
-    pub fn iter_mut(&mut self) -> slice::IterMut<'_, Vec<T>> {
-        self.row_major.iter_mut()
+    pub fn iter_mut(&mut self) -> RowsMut<'_, T> {
+        RowsMut {
+            width: self.width,
+            remaining: self.height,
+            cells: &mut self.cells,
+        }
     }
 
     pub fn cells(&self) -> impl Iterator<Item = (usize, usize, &T)> {
-        self.row_major
+        let width = self.width;
+        self.cells
             .iter()
             .enumerate()
-            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, value)| (x, y, value)))
+            .map(move |(i, value)| (i % width, i / width, value))
     }
 
     pub fn cells_mut(&mut self) -> impl Iterator<Item = (usize, usize, &mut T)> {
-        self.row_major.iter_mut().enumerate().flat_map(|(y, row)| {
-            row.iter_mut()
-                .enumerate()
-                .map(move |(x, value)| (x, y, value))
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, value)| (i % width, i / width, value))
+    }
+
+    fn index_of<I: TryInto<usize>>(&self, (x, y): (I, I)) -> Option<usize> {
+        let (x, y) = (x.try_into().ok()?, y.try_into().ok()?);
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get<I: TryInto<usize>>(&self, pos: (I, I)) -> Option<&T> {
+        Some(&self.cells[self.index_of(pos)?])
+    }
+
+    pub fn get_mut<I: TryInto<usize>>(&mut self, pos: (I, I)) -> Option<&mut T> {
+        let index = self.index_of(pos)?;
+        Some(&mut self.cells[index])
+    }
+
+    /// Position `pos` resolved on the torus formed by repeating the rect in every direction.
+    pub fn get_wrapping<I: TryInto<isize>>(&self, (x, y): (I, I)) -> Option<&T> {
+        let (x, y) = (x.try_into().ok()?, y.try_into().ok()?);
+        if self.cells.is_empty() {
+            return None;
+        }
+        self.get((
+            x.rem_euclid(self.width as isize),
+            y.rem_euclid(self.height as isize),
+        ))
+    }
+
+    fn neighbors_of<'a, D: 'a, I: TryFrom<isize>>(
+        &'a self,
+        adjacent: impl Iterator<Item = (D, (isize, isize))> + 'a,
+    ) -> impl Iterator<Item = (D, (I, I), &'a T)> + 'a {
+        adjacent.filter_map(|(dir, pos)| {
+            let value = self.get(pos)?;
+            Some((dir, (pos.0.try_into().ok()?, pos.1.try_into().ok()?), value))
         })
     }
 
-    pub fn get<I: TryInto<usize>>(&self, (x, y): (I, I)) -> Option<&T> {
-        self.row_major
-            .get(y.try_into().ok()?)?
-            .get(x.try_into().ok()?)
+    fn neighbors_of_mut<D, I: TryFrom<isize>>(
+        &mut self,
+        adjacent: impl Iterator<Item = (D, (isize, isize))>,
+    ) -> impl Iterator<Item = (D, (I, I), &mut T)> {
+        let mut found = adjacent
+            .enumerate()
+            .filter_map(|(order, (dir, pos))| {
+                let index = self.index_of(pos)?;
+                Some((
+                    index,
+                    order,
+                    dir,
+                    (pos.0.try_into().ok()?, pos.1.try_into().ok()?),
+                ))
+            })
+            .collect::<Vec<_>>();
+        // Split the distinct cells off the storage in index order, then restore the order of
+        // `adjacent`.
+        found.sort_by_key(|&(index, ..)| index);
+        let mut rest = &mut self.cells[..];
+        let mut offset = 0;
+        let mut result = Vec::with_capacity(found.len());
+        for (index, order, dir, pos) in found {
+            let (value, tail) = mem::take(&mut rest)[index - offset..]
+                .split_first_mut()
+                .unwrap();
+            rest = tail;
+            offset = index + 1;
+            result.push((order, dir, pos, value));
+        }
+        result.sort_by_key(|&(order, ..)| order);
+        result
+            .into_iter()
+            .map(|(_, dir, pos, value)| (dir, pos, value))
+    }
+
+    /// The orthogonal neighbors of `pos` that lie within the rect.
+    pub fn neighbors4<I: TryInto<isize> + TryFrom<isize>>(
+        &self,
+        pos: (I, I),
+    ) -> impl Iterator<Item = (Direction4, (I, I), &T)> {
+        self.neighbors_of(
+            signed(pos)
+                .into_iter()
+                .flat_map(|pos| Direction4::iter().map(move |dir| (dir, dir.advance(pos)))),
+        )
     }
 
-    pub fn get_mut<I: TryInto<usize>>(&mut self, (x, y): (I, I)) -> Option<&mut T> {
-        self.row_major
-            .get_mut(y.try_into().ok()?)?
-            .get_mut(x.try_into().ok()?)
+    /// The orthogonal and diagonal neighbors of `pos` that lie within the rect.
+    pub fn neighbors8<I: TryInto<isize> + TryFrom<isize>>(
+        &self,
+        pos: (I, I),
+    ) -> impl Iterator<Item = (Direction8, (I, I), &T)> {
+        self.neighbors_of(
+            signed(pos)
+                .into_iter()
+                .flat_map(|pos| Direction8::iter().map(move |dir| (dir, dir.advance(pos)))),
+        )
+    }
+
+    pub fn neighbors4_mut<I: TryInto<isize> + TryFrom<isize>>(
+        &mut self,
+        pos: (I, I),
+    ) -> impl Iterator<Item = (Direction4, (I, I), &mut T)> {
+        self.neighbors_of_mut(
+            signed(pos)
+                .into_iter()
+                .flat_map(|pos| Direction4::iter().map(move |dir| (dir, dir.advance(pos)))),
+        )
     }
+
+    pub fn neighbors8_mut<I: TryInto<isize> + TryFrom<isize>>(
+        &mut self,
+        pos: (I, I),
+    ) -> impl Iterator<Item = (Direction8, (I, I), &mut T)> {
+        self.neighbors_of_mut(
+            signed(pos)
+                .into_iter()
+                .flat_map(|pos| Direction8::iter().map(move |dir| (dir, dir.advance(pos)))),
+        )
+    }
+
+    /// The orthogonal neighbors of `pos` on the torus formed by repeating the rect in every
+    /// direction. Positions are not wrapped, only the values are looked up with `get_wrapping`.
+    pub fn neighbors4_wrapping(
+        &self,
+        pos: (isize, isize),
+    ) -> impl Iterator<Item = (Direction4, (isize, isize), &T)> {
+        Direction4::iter().filter_map(move |dir| {
+            let pos = dir.advance(pos);
+            Some((dir, pos, self.get_wrapping(pos)?))
+        })
+    }
+
+    /// The orthogonal and diagonal counterpart of `neighbors4_wrapping`.
+    pub fn neighbors8_wrapping(
+        &self,
+        pos: (isize, isize),
+    ) -> impl Iterator<Item = (Direction8, (isize, isize), &T)> {
+        Direction8::iter().filter_map(move |dir| {
+            let pos = dir.advance(pos);
+            Some((dir, pos, self.get_wrapping(pos)?))
+        })
+    }
+
+    /// Mirrors the rect along its main diagonal, so that `(x, y)` moves to `(y, x)`. Matches
+    /// `Direction4::reflect_nwse`. Square rects are transposed without reallocating.
+    pub fn transpose(&mut self) {
+        if self.width == self.height {
+            let n = self.width;
+            for y in 0..n {
+                for x in y + 1..n {
+                    self.cells.swap(y * n + x, x * n + y);
+                }
+            }
+        } else {
+            let mut old: Vec<Option<T>> =
+                mem::take(&mut self.cells).into_iter().map(Some).collect();
+            for x in 0..self.width {
+                for y in 0..self.height {
+                    self.cells.push(old[y * self.width + x].take().unwrap());
+                }
+            }
+            mem::swap(&mut self.width, &mut self.height);
+        }
+    }
+
+    /// Mirrors the rect left to right. Matches `Direction4::reflect_h`.
+    pub fn flip_h(&mut self) {
+        for row in self.iter_mut() {
+            row.reverse();
+        }
+    }
+
+    /// Mirrors the rect top to bottom. Matches `Direction4::reflect_v`.
+    pub fn flip_v(&mut self) {
+        self.rotate_180();
+        self.flip_h();
+    }
+
+    /// Rotates the rect a quarter turn clockwise, so that the north edge becomes the east edge.
+    /// Matches `Direction4::rotate_cw`.
+    pub fn rotate_cw(&mut self) {
+        self.transpose();
+        self.flip_h();
+    }
+
+    /// Rotates the rect a quarter turn counterclockwise. Matches `Direction4::rotate_ccw`.
+    pub fn rotate_ccw(&mut self) {
+        self.transpose();
+        self.flip_v();
+    }
+
+    /// Matches `Direction4::opposite`.
+    pub fn rotate_180(&mut self) {
+        self.cells.reverse();
+    }
+
+    /// Draws the rect with one line per row and `f` choosing the character for each cell.
+    pub fn render<F: Fn(&T) -> char>(&self, f: F) -> String {
+        self.render_overlay(f, None::<(usize, usize)>, ' ')
+    }
+
+    /// Like `render`, but draws `mark` at every position of `overlay` that lies within the rect.
+    pub fn render_overlay<F, I, P>(&self, f: F, overlay: P, mark: char) -> String
+    where
+        F: Fn(&T) -> char,
+        I: TryInto<usize>,
+        P: IntoIterator<Item = (I, I)>,
+    {
+        let mut chars = Rect {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        };
+        for pos in overlay {
+            if let Some(c) = chars.get_mut(pos) {
+                *c = mark;
+            }
+        }
+        chars.to_string()
+    }
+}
+
+fn signed<I: TryInto<isize>>((x, y): (I, I)) -> Option<(isize, isize)> {
+    Some((x.try_into().ok()?, y.try_into().ok()?))
 }
 
 impl<T, I: TryInto<usize>> Index<(I, I)> for Rect<T> {
@@ -112,145 +434,225 @@ impl<T, I: TryInto<usize>> IndexMut<(I, I)> for Rect<T> {
     }
 }
 
-impl<T> IntoIterator for Rect<T> {
-    type Item = Vec<T>;
-    type IntoIter = vec::IntoIter<Vec<T>>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.row_major.into_iter()
+impl<T: Debug> Debug for Rect<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Rect {}x{}", self.width, self.height)?;
+        for row in self.iter() {
+            for (x, cell) in row.iter().enumerate() {
+                if x > 0 {
+                    write!(f, " ")?;
+                }
+                cell.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
     }
 }
 
-impl<T> FromIterator<Vec<T>> for Rect<T> {
-    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
-        let mut iter = iter.into_iter();
-        match iter.next() {
-            None => Rect {
-                width: 0,
-                row_major: Vec::new(),
-            },
-            Some(first_row) => {
-                let mut rect = Rect {
-                    width: first_row.len(),
-                    row_major: vec![first_row],
-                };
-                rect.row_major.reserve(iter.size_hint().0);
-                for row in iter {
-                    rect.push_row(row)
-                }
-                rect
-            }
+impl Display for Rect<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
         }
+        Ok(())
     }
 }
 
-pub struct Transposed<T>(pub T);
+impl TryFrom<&str> for Rect<char> {
+    type Error = ParseError;
 
-pub struct TransposedIter<T> {
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Rect::parse_grid(input, Ok::<_, Infallible>)
+    }
+}
+
+pub struct Rows<'a, T> {
+    width: usize,
     remaining: usize,
-    iterators: Vec<vec::IntoIter<T>>,
+    cells: &'a [T],
 }
 
-impl<T> Iterator for TransposedIter<T> {
-    type Item = Vec<T>;
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a [T];
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.remaining == 0 {
             None
         } else {
             self.remaining -= 1;
-            Some(
-                self.iterators
-                    .iter_mut()
-                    .map(|row| row.next().unwrap())
-                    .collect(),
-            )
+            let (row, rest) = self.cells.split_at(self.width);
+            self.cells = rest;
+            Some(row)
         }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.len(), Some(self.len()))
     }
+}
 
-    fn count(self) -> usize {
-        self.len()
+impl<'a, T> FusedIterator for Rows<'a, T> {}
+
+impl<'a, T> ExactSizeIterator for Rows<'a, T> {
+    fn len(&self) -> usize {
+        self.remaining
     }
+}
 
-    fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        if self.remaining <= n {
+impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
             None
         } else {
-            self.remaining -= n;
             self.remaining -= 1;
-            Some(
-                self.iterators
-                    .iter_mut()
-                    .map(|row| row.nth(n).unwrap())
-                    .collect(),
-            )
+            let (rest, row) = self.cells.split_at(self.width * self.remaining);
+            self.cells = rest;
+            Some(row)
+        }
+    }
+}
+
+pub struct RowsMut<'a, T> {
+    width: usize,
+    remaining: usize,
+    cells: &'a mut [T],
+}
+
+impl<'a, T> Iterator for RowsMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            None
+        } else {
+            self.remaining -= 1;
+            let (row, rest) = mem::take(&mut self.cells).split_at_mut(self.width);
+            self.cells = rest;
+            Some(row)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
 }
 
-impl<T> FusedIterator for TransposedIter<T> {}
+impl<'a, T> FusedIterator for RowsMut<'a, T> {}
 
-impl<T> ExactSizeIterator for TransposedIter<T> {
+impl<'a, T> ExactSizeIterator for RowsMut<'a, T> {
     fn len(&self) -> usize {
         self.remaining
     }
 }
 
-impl<T> DoubleEndedIterator for TransposedIter<T> {
+impl<'a, T> DoubleEndedIterator for RowsMut<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
         if self.remaining == 0 {
             None
         } else {
             self.remaining -= 1;
-            Some(
-                self.iterators
-                    .iter_mut()
-                    .map(|row| row.next_back().unwrap())
-                    .collect(),
-            )
+            let (rest, row) = mem::take(&mut self.cells).split_at_mut(self.width * self.remaining);
+            self.cells = rest;
+            Some(row)
         }
     }
+}
 
-    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        if self.remaining <= n {
+pub struct IntoRows<T> {
+    width: usize,
+    remaining: usize,
+    cells: vec::IntoIter<T>,
+}
+
+impl<T> Iterator for IntoRows<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
             None
         } else {
-            self.remaining -= n;
             self.remaining -= 1;
-            Some(
-                self.iterators
-                    .iter_mut()
-                    .map(|row| row.nth_back(n).unwrap())
-                    .collect(),
-            )
+            Some(self.cells.by_ref().take(self.width).collect())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<T> FusedIterator for IntoRows<T> {}
+
+impl<T> ExactSizeIterator for IntoRows<T> {
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoRows<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            None
+        } else {
+            self.remaining -= 1;
+            let mut row: Vec<T> = self.cells.by_ref().rev().take(self.width).collect();
+            row.reverse();
+            Some(row)
+        }
+    }
+}
+
+impl<T> IntoIterator for Rect<T> {
+    type Item = Vec<T>;
+    type IntoIter = IntoRows<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoRows {
+            width: self.width,
+            remaining: self.height,
+            cells: self.cells.into_iter(),
         }
     }
 }
 
+impl<T> FromIterator<Vec<T>> for Rect<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        match iter.next() {
+            None => Rect::new_wide(0),
+            Some(first_row) => {
+                let mut rect = Rect::new_wide(first_row.len());
+                rect.cells
+                    .reserve(first_row.len() * (1 + iter.size_hint().0));
+                rect.push_row(first_row);
+                for row in iter {
+                    rect.push_row(row)
+                }
+                rect
+            }
+        }
+    }
+}
+
+pub struct Transposed<T>(pub T);
+
+pub type TransposedIter<T> = IntoRows<T>;
+
 impl<T> IntoIterator for Transposed<Rect<T>> {
     type Item = Vec<T>;
     type IntoIter = TransposedIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        TransposedIter {
-            remaining: self.0.width,
-            iterators: self
-                .0
-                .row_major
-                .into_iter()
-                .map(|row| row.into_iter())
-                .collect(),
-        }
+        let mut rect = self.0;
+        rect.transpose();
+        rect.into_iter()
     }
 }
 
 impl<'a, T> Transposed<&'a Rect<T>> {
     pub fn iter(self) -> impl Iterator<Item = impl Iterator<Item = &'a T>> {
-        (0..self.0.width).map(|x| self.0.row_major.iter().map(move |row| &row[x]))
+        (0..self.0.width).map(|x| self.0.col(x))
     }
 }
 
@@ -343,42 +745,15 @@ impl<'a, T> Transposed<&'a mut Rect<T>> {
     pub fn iter_mut(self) -> TransposedIterMut<'a, T> {
         TransposedIterMut {
             remaining: self.0.width,
-            iterators: self
-                .0
-                .row_major
-                .iter_mut()
-                .map(|row| row.iter_mut())
-                .collect(),
+            iterators: self.0.iter_mut().map(|row| row.iter_mut()).collect(),
         }
     }
 }
 
 impl<T> FromIterator<Vec<T>> for Transposed<Rect<T>> {
     fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
-        let mut iter = iter.into_iter();
-        match iter.next() {
-            None => Transposed(Rect {
-                width: 0,
-                row_major: Vec::new(),
-            }),
-            Some(first_col) => {
-                let (width_hint, _) = iter.size_hint();
-                let mut rect = Rect {
-                    width: 1,
-                    row_major: first_col
-                        .into_iter()
-                        .map(|cell| {
-                            let mut vec = vec![cell];
-                            vec.reserve(width_hint);
-                            vec
-                        })
-                        .collect(),
-                };
-                for col in iter {
-                    rect.push_col(col)
-                }
-                Transposed(rect)
-            }
-        }
+        let mut rect: Rect<T> = iter.into_iter().collect();
+        rect.transpose();
+        Transposed(rect)
     }
 }
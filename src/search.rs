@@ -0,0 +1,239 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::rect::Rect;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SearchResult<S, C> {
+    pub cost: C,
+    /// States from the start to the goal, both included.
+    pub path: Vec<S>,
+    /// Number of states expanded before the goal was reached.
+    pub visited: usize,
+}
+
+/// Every state pushed during a search, with the entry it was reached from.
+struct Trail<S> {
+    entries: Vec<(S, Option<usize>)>,
+}
+
+impl<S: Clone> Trail<S> {
+    fn push(&mut self, state: S, parent: Option<usize>) -> usize {
+        self.entries.push((state, parent));
+        self.entries.len() - 1
+    }
+
+    fn path(&self, mut index: usize) -> Vec<S> {
+        let mut path = vec![self.entries[index].0.clone()];
+        while let Some(parent) = self.entries[index].1 {
+            path.push(self.entries[parent].0.clone());
+            index = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+fn hash_set_insert<S: Clone + Eq + Hash>() -> impl FnMut(&S) -> bool {
+    let mut seen = HashSet::new();
+    move |state: &S| seen.insert(state.clone())
+}
+
+/// Breadth-first search where every move costs 1.
+pub fn bfs<S, I, FS, FG>(start: S, successors: FS, goal: FG) -> Option<SearchResult<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> I,
+    FG: FnMut(&S) -> bool,
+{
+    bfs_with(start, successors, goal, hash_set_insert())
+}
+
+/// Like `bfs`, with the set of seen states kept by `insert`, which adds a state and returns
+/// whether it was new.
+pub fn bfs_with<S, I, FS, FG, FV>(
+    start: S,
+    mut successors: FS,
+    mut goal: FG,
+    mut insert: FV,
+) -> Option<SearchResult<S, usize>>
+where
+    S: Clone,
+    I: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> I,
+    FG: FnMut(&S) -> bool,
+    FV: FnMut(&S) -> bool,
+{
+    let mut trail = Trail {
+        entries: Vec::new(),
+    };
+    insert(&start);
+    let mut queue = VecDeque::from([(trail.push(start, None), 0)]);
+    let mut visited = 0;
+    while let Some((index, cost)) = queue.pop_front() {
+        visited += 1;
+        let state = &trail.entries[index].0;
+        if goal(state) {
+            return Some(SearchResult {
+                cost,
+                path: trail.path(index),
+                visited,
+            });
+        }
+        for next in successors(state) {
+            if insert(&next) {
+                queue.push_back((trail.push(next, Some(index)), cost + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm. `successors` gives the states reachable from a state together with the
+/// cost of each move, which must not be negative.
+pub fn dijkstra<S, C, I, FS, FG>(start: S, successors: FS, goal: FG) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + From<bool>,
+    I: IntoIterator<Item = (S, C)>,
+    FS: FnMut(&S) -> I,
+    FG: FnMut(&S) -> bool,
+{
+    astar_with(
+        start,
+        successors,
+        |_| C::from(false),
+        goal,
+        hash_set_insert(),
+    )
+}
+
+/// Like `dijkstra`, with the set of expanded states kept by `insert` as in `bfs_with`.
+pub fn dijkstra_with<S, C, I, FS, FG, FV>(
+    start: S,
+    successors: FS,
+    goal: FG,
+    insert: FV,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone,
+    C: Copy + Ord + Add<Output = C> + From<bool>,
+    I: IntoIterator<Item = (S, C)>,
+    FS: FnMut(&S) -> I,
+    FG: FnMut(&S) -> bool,
+    FV: FnMut(&S) -> bool,
+{
+    astar_with(start, successors, |_| C::from(false), goal, insert)
+}
+
+/// A* search. `heuristic` estimates the remaining cost to a goal, and must be consistent: it may
+/// drop by no more than the cost of any move.
+pub fn astar<S, C, I, FS, FH, FG>(
+    start: S,
+    successors: FS,
+    heuristic: FH,
+    goal: FG,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + From<bool>,
+    I: IntoIterator<Item = (S, C)>,
+    FS: FnMut(&S) -> I,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    astar_with(start, successors, heuristic, goal, hash_set_insert())
+}
+
+/// Like `astar`, with the set of expanded states kept by `insert` as in `bfs_with`.
+pub fn astar_with<S, C, I, FS, FH, FG, FV>(
+    start: S,
+    mut successors: FS,
+    mut heuristic: FH,
+    mut goal: FG,
+    mut insert: FV,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone,
+    C: Copy + Ord + Add<Output = C> + From<bool>,
+    I: IntoIterator<Item = (S, C)>,
+    FS: FnMut(&S) -> I,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+    FV: FnMut(&S) -> bool,
+{
+    let mut trail = Trail {
+        entries: Vec::new(),
+    };
+    let mut queue = BinaryHeap::new();
+    let estimate = heuristic(&start);
+    queue.push(Reverse((estimate, C::from(false), trail.push(start, None))));
+    let mut visited = 0;
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let state = &trail.entries[index].0;
+        if !insert(state) {
+            continue;
+        }
+        visited += 1;
+        if goal(state) {
+            return Some(SearchResult {
+                cost,
+                path: trail.path(index),
+                visited,
+            });
+        }
+        for (next, step) in successors(state) {
+            let cost = cost + step;
+            let estimate = cost + heuristic(&next);
+            queue.push(Reverse((estimate, cost, trail.push(next, Some(index)))));
+        }
+    }
+    None
+}
+
+/// Shortest path between two cells of `rect` using orthogonal moves into cells accepted by
+/// `passable`.
+pub fn grid_bfs<T, F: Fn(&T) -> bool>(
+    rect: &Rect<T>,
+    start: (usize, usize),
+    goal: (usize, usize),
+    passable: F,
+) -> Option<SearchResult<(usize, usize), usize>> {
+    bfs(
+        start,
+        |&pos| {
+            rect.neighbors4(pos)
+                .filter_map(|(_, pos, value)| passable(value).then_some(pos))
+                .collect::<Vec<_>>()
+        },
+        |&pos| pos == goal,
+    )
+}
+
+/// Cheapest path between two cells of `rect` using orthogonal moves, where `cost` gives the cost
+/// of entering a cell, or `None` if it cannot be entered.
+pub fn grid_dijkstra<T, C, F>(
+    rect: &Rect<T>,
+    start: (usize, usize),
+    goal: (usize, usize),
+    cost: F,
+) -> Option<SearchResult<(usize, usize), C>>
+where
+    C: Copy + Ord + Add<Output = C> + From<bool>,
+    F: Fn(&T) -> Option<C>,
+{
+    dijkstra(
+        start,
+        |&pos| {
+            rect.neighbors4(pos)
+                .filter_map(|(_, pos, value)| Some((pos, cost(value)?)))
+                .collect::<Vec<_>>()
+        },
+        |&pos| pos == goal,
+    )
+}
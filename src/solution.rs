@@ -0,0 +1,98 @@
+use std::{any::Any, error::Error, fmt};
+
+use crate::{bigint::BigInt, parse::ParseError};
+
+/// A reason a part has no answer for an input that parsed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SolveError {
+    message: String,
+}
+
+impl SolveError {
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        SolveError {
+            message: message.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for SolveError {}
+
+/// What a part returns: an answer that can be displayed, or a `Result` of one for parts that can
+/// fail.
+pub trait Answer {
+    fn into_answer(self) -> Result<String, SolveError>;
+}
+
+macro_rules! impl_answer {
+    ($($t:ty),*) => {
+        $(
+            impl Answer for $t {
+                fn into_answer(self) -> Result<String, SolveError> {
+                    Ok(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_answer!(String, &str, BigInt);
+
+impl<T: Answer> Answer for Result<T, SolveError> {
+    fn into_answer(self) -> Result<String, SolveError> {
+        self?.into_answer()
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(&self, input: &Self::Input) -> impl Answer;
+
+    fn part2(&self, input: &Self::Input) -> impl Answer;
+}
+
+/// Object-safe view of a `Solution` with the input type erased, so that days with different
+/// input types can be stored side by side and each stage can still be called separately.
+pub trait DynSolution {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    fn part1(&self, input: &dyn Any) -> Result<String, SolveError>;
+
+    fn part2(&self, input: &dyn Any) -> Result<String, SolveError>;
+
+    fn solve(&self, input: &str) -> Result<(String, String), Box<dyn Error>> {
+        let input = self.parse(input)?;
+        Ok((self.part1(&*input)?, self.part2(&*input)?))
+    }
+}
+
+impl<S: Solution> DynSolution for S
+where
+    S::Input: 'static,
+{
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part1(&self, input: &dyn Any) -> Result<String, SolveError> {
+        Solution::part1(self, input.downcast_ref().unwrap()).into_answer()
+    }
+
+    fn part2(&self, input: &dyn Any) -> Result<String, SolveError> {
+        Solution::part2(self, input.downcast_ref().unwrap()).into_answer()
+    }
+}
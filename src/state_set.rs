@@ -0,0 +1,168 @@
+use crate::{direction::Direction4, rect::Rect};
+
+/// A set of states `(pos, dir, extra)` of something moving on a `width` by `height` grid, where
+/// `extra` is a counter below `N`. Every state takes up a single bit.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GridStateSet<const N: usize> {
+    width: usize,
+    height: usize,
+    bits: Vec<u64>,
+}
+
+const STATES_PER_CELL: usize = 4;
+
+fn cell_index<I: TryInto<usize>>(width: usize, height: usize, (x, y): (I, I)) -> Option<usize> {
+    let (x, y) = (x.try_into().ok()?, y.try_into().ok()?);
+    (x < width && y < height).then_some(y * width + x)
+}
+
+/// Sets a bit, returning whether it was clear before.
+fn set_bit(bits: &mut [u64], bit: usize) -> bool {
+    let word = &mut bits[bit / 64];
+    let mask = 1 << (bit % 64);
+    let new = *word & mask == 0;
+    *word |= mask;
+    new
+}
+
+fn test_bit(bits: &[u64], bit: usize) -> bool {
+    bits[bit / 64] & 1 << (bit % 64) != 0
+}
+
+fn count_bits(bits: &[u64]) -> usize {
+    bits.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+impl<const N: usize> GridStateSet<N> {
+    pub fn new(width: usize, height: usize) -> Self {
+        GridStateSet {
+            width,
+            height,
+            bits: vec![0; (width * height * STATES_PER_CELL * N).div_ceil(64)],
+        }
+    }
+
+    /// An empty set of states on the same grid as `rect`.
+    pub fn for_rect<T>(rect: &Rect<T>) -> Self {
+        Self::new(rect.width(), rect.height())
+    }
+
+    fn cell_index<I: TryInto<usize>>(&self, pos: (I, I)) -> Option<usize> {
+        cell_index(self.width, self.height, pos)
+    }
+
+    fn bit_index<I: TryInto<usize>>(
+        &self,
+        pos: (I, I),
+        dir: Direction4,
+        extra: usize,
+    ) -> Option<usize> {
+        assert!(extra < N);
+        Some((self.cell_index(pos)? * STATES_PER_CELL + dir as usize) * N + extra)
+    }
+
+    /// Adds a state, returning whether it was not present before. Panics if `pos` is outside
+    /// the grid.
+    pub fn insert<I: TryInto<usize>>(
+        &mut self,
+        pos: (I, I),
+        dir: Direction4,
+        extra: usize,
+    ) -> bool {
+        let bit = self.bit_index(pos, dir, extra).unwrap();
+        set_bit(&mut self.bits, bit)
+    }
+
+    pub fn contains<I: TryInto<usize>>(&self, pos: (I, I), dir: Direction4, extra: usize) -> bool {
+        self.bit_index(pos, dir, extra)
+            .is_some_and(|bit| test_bit(&self.bits, bit))
+    }
+
+    /// Whether any state at `pos` is present.
+    pub fn contains_pos<I: TryInto<usize>>(&self, pos: (I, I)) -> bool {
+        self.cell_index(pos).is_some_and(|cell| {
+            (cell * STATES_PER_CELL * N..(cell + 1) * STATES_PER_CELL * N)
+                .any(|bit| test_bit(&self.bits, bit))
+        })
+    }
+
+    /// Positions at which any state is present, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&pos| self.contains_pos(pos))
+    }
+
+    pub fn len(&self) -> usize {
+        count_bits(&self.bits)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+}
+
+/// A set of cells of a `width` by `height` grid, one bit per cell.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct GridCellSet {
+    width: usize,
+    height: usize,
+    bits: Vec<u64>,
+}
+
+impl GridCellSet {
+    pub fn new(width: usize, height: usize) -> Self {
+        GridCellSet {
+            width,
+            height,
+            bits: vec![0; (width * height).div_ceil(64)],
+        }
+    }
+
+    /// An empty set of cells on the same grid as `rect`.
+    pub fn for_rect<T>(rect: &Rect<T>) -> Self {
+        Self::new(rect.width(), rect.height())
+    }
+
+    /// Adds a cell, returning whether it was not present before. Panics if `pos` is outside the
+    /// grid.
+    pub fn insert<I: TryInto<usize>>(&mut self, pos: (I, I)) -> bool {
+        let bit = cell_index(self.width, self.height, pos).unwrap();
+        set_bit(&mut self.bits, bit)
+    }
+
+    pub fn contains<I: TryInto<usize>>(&self, pos: (I, I)) -> bool {
+        cell_index(self.width, self.height, pos).is_some_and(|bit| test_bit(&self.bits, bit))
+    }
+
+    /// Adds every cell of `other`, which must be on a grid of the same size.
+    pub fn union_with(&mut self, other: &GridCellSet) {
+        assert_eq!((self.width, self.height), (other.width, other.height));
+        for (word, &other) in self.bits.iter_mut().zip(&other.bits) {
+            *word |= other;
+        }
+    }
+
+    /// The cells in the set, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+            .filter(|&pos| self.contains(pos))
+    }
+
+    pub fn len(&self) -> usize {
+        count_bits(&self.bits)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+}
@@ -0,0 +1,63 @@
+use utils::{
+    bigint::BigInt,
+    numbers::{bezout_identity, gcd, lcm},
+};
+
+mod common;
+
+fn values() -> Vec<i128> {
+    let mut values = vec![0, 1, -1, 2, -7, 1 << 32, -(1 << 32) + 1, u32::MAX as i128];
+    values.extend(
+        common::lcg(12345)
+            .take(40)
+            .map(|state| (state >> (state % 48)) as i64 as i128),
+    );
+    values
+}
+
+#[test]
+fn matches_i128() {
+    for &a in &values() {
+        for &b in &values() {
+            let (x, y) = (BigInt::from(a), BigInt::from(b));
+            assert_eq!(&x + &y, BigInt::from(a + b));
+            assert_eq!(&x - &y, BigInt::from(a - b));
+            assert_eq!(&x * &y, BigInt::from(a * b));
+            assert_eq!(x.cmp(&y), a.cmp(&b));
+            if b != 0 {
+                assert_eq!(&x / &y, BigInt::from(a / b), "{} / {}", a, b);
+                assert_eq!(&x % &y, BigInt::from(a % b), "{} % {}", a, b);
+            }
+        }
+        assert_eq!(BigInt::from(a).to_string(), a.to_string());
+        assert_eq!(a.to_string().parse::<BigInt>(), Ok(BigInt::from(a)));
+    }
+}
+
+#[test]
+fn beyond_i128() {
+    let big: BigInt = "340282366920938463463374607431768211457".parse().unwrap();
+    let product = &big * &big;
+    assert_eq!(
+        product.to_string(),
+        "115792089237316195423570985008687907853950549399482440966384333222776666062849"
+    );
+    assert_eq!(&product / &big, big);
+    assert_eq!(&(&product + BigInt::from(5)) % &big, BigInt::from(5));
+    assert_eq!(format!("{:>8}", BigInt::from(-42)), "     -42");
+    assert!("12a".parse::<BigInt>().is_err() && "-".parse::<BigInt>().is_err());
+}
+
+#[test]
+fn numbers_functions() {
+    let p: BigInt = "170141183460469231731687303715884105727".parse().unwrap();
+    let q: BigInt = "618970019642690137449562111".parse().unwrap();
+    let r = BigInt::from(1_000_000_007u64);
+    assert_eq!(gcd(&p * &r, &q * &r), r);
+    assert_eq!(lcm(&p * &r, &q * &r), &p * &q * &r);
+
+    let e = bezout_identity(p.clone(), q.clone());
+    assert_eq!(e.gcd, BigInt::from(1));
+    let combination = &p * &e.k_a - &q * &e.k_b;
+    assert_eq!(if e.sign { -combination } else { combination }, e.gcd);
+}
@@ -0,0 +1,11 @@
+/// Successive states of a 64-bit linear congruential generator started from `seed`, for
+/// reproducible pseudo-random test cases.
+pub fn lcg(seed: u64) -> impl Iterator<Item = u64> {
+    let mut state = seed;
+    std::iter::repeat_with(move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        state
+    })
+}
@@ -0,0 +1,18 @@
+use utils::{
+    days::day14::Day14,
+    solution::{Answer, Solution},
+};
+
+fn part2(input: &str) -> String {
+    let board = Day14.parse(input).unwrap();
+    let answer = Day14.part2(&board).into_answer().unwrap();
+    answer
+}
+
+#[test]
+fn boards_differing_only_in_orientation() {
+    // Intermediate tilts of these boards have the same rolling rocks as earlier ones in a
+    // different orientation.
+    assert_eq!(part2("..\n##\n.O\n..\n"), "1");
+    assert_eq!(part2("#..\n...\n.#O\n"), "2");
+}
@@ -0,0 +1,101 @@
+use std::collections::HashSet;
+
+use utils::{
+    days::day16::Day16,
+    solution::{Answer, Solution},
+};
+
+mod common;
+
+/// A pseudo-random board with sparse mirrors and splitters, from a fixed seed.
+fn board(width: usize, height: usize, seed: u64) -> String {
+    let mut board = String::with_capacity((width + 1) * height);
+    let mut states = common::lcg(seed);
+    for _ in 0..height {
+        for _ in 0..width {
+            board.push(match (states.next().unwrap() >> 33) % 40 {
+                0 => '|',
+                1 => '-',
+                2 => '/',
+                3 => '\\',
+                _ => '.',
+            });
+        }
+        board.push('\n');
+    }
+    board
+}
+
+/// Energized cells for a beam entering `start` towards `dir`, following every beam one step at
+/// a time.
+fn reference(board: &[&[u8]], start: (isize, isize), dir: (isize, isize)) -> usize {
+    let mut seen = HashSet::new();
+    let mut work = vec![(start, dir)];
+    while let Some(((x, y), (dx, dy))) = work.pop() {
+        let Some(&c) = board.get(y as usize).and_then(|row| row.get(x as usize)) else {
+            continue;
+        };
+        if x < 0 || y < 0 || !seen.insert(((x, y), (dx, dy))) {
+            continue;
+        }
+        let dirs = match c {
+            b'/' => vec![(-dy, -dx)],
+            b'\\' => vec![(dy, dx)],
+            b'|' if dx != 0 => vec![(0, 1), (0, -1)],
+            b'-' if dy != 0 => vec![(1, 0), (-1, 0)],
+            _ => vec![(dx, dy)],
+        };
+        work.extend(
+            dirs.into_iter()
+                .map(|(dx, dy)| ((x + dx, y + dy), (dx, dy))),
+        );
+    }
+    seen.iter()
+        .map(|&(pos, _)| pos)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+fn reference_best(board: &[&[u8]]) -> usize {
+    let (width, height) = (board[0].len() as isize, board.len() as isize);
+    let edges = (0..width)
+        .flat_map(|x| [((x, 0), (0, 1)), ((x, height - 1), (0, -1))])
+        .chain((0..height).flat_map(|y| [((0, y), (1, 0)), ((width - 1, y), (-1, 0))]));
+    edges
+        .map(|(start, dir)| reference(board, start, dir))
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn matches_reference() {
+    for seed in 0..20 {
+        let input = board(30, 20, seed);
+        let rows = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+        let parsed = Day16.parse(&input).unwrap();
+        assert_eq!(
+            Day16.part1(&parsed).into_answer().unwrap(),
+            reference(&rows, (0, 0), (1, 0)).to_string(),
+            "seed {}",
+            seed
+        );
+        assert_eq!(
+            Day16.part2(&parsed).into_answer().unwrap(),
+            reference_best(&rows).to_string(),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn large_board() {
+    let input = board(1000, 1000, 16);
+    let rows = input.lines().map(str::as_bytes).collect::<Vec<_>>();
+    let parsed = Day16.parse(&input).unwrap();
+    let part1 = Day16.part1(&parsed).into_answer().unwrap();
+    assert_eq!(part1, reference(&rows, (0, 0), (1, 0)).to_string());
+    // Too slow to check against `reference_best` here. The value was checked once against a
+    // separate brute force that follows every beam of every entry point step by step.
+    assert_eq!(Day16.part2(&parsed).into_answer().unwrap(), "829506");
+}
@@ -0,0 +1,78 @@
+use std::fs;
+
+use utils::{
+    days::day17::{best_path, CrucibleRules, Day17},
+    solution::{Answer, Solution, SolveError},
+};
+
+#[test]
+fn path_follows_rules() {
+    let input = fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/examples/inputs/17.txt"
+    ))
+    .unwrap();
+    let field = Day17.parse(&input).unwrap();
+    for rules in [
+        CrucibleRules::CRUCIBLE,
+        CrucibleRules::ULTRA_CRUCIBLE,
+        CrucibleRules {
+            min_straight: 2,
+            max_straight: 20,
+            can_stop_early: false,
+        },
+    ] {
+        let best = best_path(&field, rules).unwrap();
+        assert_eq!(best.path.first(), Some(&(0, 0)));
+        assert_eq!(
+            best.path.last(),
+            Some(&(field.width() - 1, field.height() - 1))
+        );
+        let loss: u32 = best.path[1..].iter().map(|&pos| field[pos] as u32).sum();
+        assert_eq!(loss, best.heat_loss);
+        let mut run = 0;
+        let mut last_step = None;
+        for step in best.path.windows(2) {
+            let (dx, dy) = (
+                step[1].0 as isize - step[0].0 as isize,
+                step[1].1 as isize - step[0].1 as isize,
+            );
+            assert_eq!(dx.abs() + dy.abs(), 1);
+            if last_step == Some((dx, dy)) {
+                run += 1;
+            } else {
+                assert!(last_step.is_none() || run >= rules.min_straight);
+                run = 1;
+            }
+            assert!(run <= rules.max_straight);
+            last_step = Some((dx, dy));
+        }
+        assert!(rules.can_stop_early || run >= rules.min_straight);
+    }
+}
+
+#[test]
+fn no_path() {
+    let field = Day17.parse("12\n34").unwrap();
+    assert_eq!(Day17.part1(&field).into_answer().unwrap(), "6");
+    assert_eq!(
+        Day17.part2(&field).into_answer(),
+        Err(SolveError::new("no path"))
+    );
+}
+
+#[test]
+fn longest_straight() {
+    let rules = CrucibleRules {
+        min_straight: 1,
+        max_straight: u8::MAX,
+        can_stop_early: true,
+    };
+    let field = Day17.parse(&"1".repeat(256)).unwrap();
+    assert_eq!(
+        best_path(&field, rules).map(|best| best.heat_loss),
+        Some(255)
+    );
+    let field = Day17.parse(&"1".repeat(257)).unwrap();
+    assert_eq!(best_path(&field, rules), None);
+}
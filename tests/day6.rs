@@ -0,0 +1,21 @@
+use utils::{
+    days::day6::Day6,
+    solution::{Answer, Solution},
+};
+
+#[test]
+fn near_perfect_square() {
+    // Floating point square roots miscount the boundaries of this race by one on each side.
+    let races = Day6
+        .parse("Time:      2853513164\nDistance:  2020885905112627618\n")
+        .unwrap();
+    assert_eq!(Day6.part1(&races).into_answer().unwrap(), "242886305");
+}
+
+#[test]
+fn long_race() {
+    // The square of the time no longer fits in an `i64`.
+    let races = Day6.parse("Time: 5000000000\nDistance: 1000\n").unwrap();
+    assert_eq!(Day6.part1(&races).into_answer().unwrap(), "4999999999");
+    assert_eq!(Day6.part2(&races).into_answer().unwrap(), "4999999999");
+}
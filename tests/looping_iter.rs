@@ -0,0 +1,125 @@
+use itertools::iterate;
+
+use utils::{
+    looping_iter::{BrentCycle, Delooping, Looping, LoopingIter, SyncLoopingIter},
+    periodic::PeriodicSet,
+};
+
+#[test]
+fn delooping() {
+    let (init, cycle) = iterate(3u32, |&x| x * x % 100).delooping().loop_structure();
+    assert_eq!(init, [3, 9]);
+    assert_eq!(cycle, [81, 61, 21, 41]);
+    assert_eq!((0..5).delooping().count(), 5);
+}
+
+#[test]
+fn delooping_by_key() {
+    // Only the first component decides whether an item repeats.
+    let mut iter =
+        iterate((0u32, 0u32), |&(a, b)| ((a + 1) % 4, b + 1)).delooping_by_key(|&(a, _)| a);
+    assert_eq!(iter.nth(9), Some((1, 1)));
+    let (init, cycle) = iter.loop_structure();
+    assert!(init.is_empty());
+    assert_eq!(cycle, [(0, 0), (1, 1), (2, 2), (3, 3)]);
+}
+
+#[test]
+fn delooping_by_key_verified() {
+    let step = |&(a, b): &(u32, u32)| ((a + 1) % 4, (b + 1) % 6);
+    let unverified = iterate((0, 0), step).delooping_by_key(|&(a, _)| a);
+    assert_eq!((unverified.prefix_len(), unverified.period()), (0, Some(4)));
+
+    // Every key collides three times per period before the first real repeat.
+    let mut verified = iterate((0, 0), step).delooping_by_key_verified(|&(a, _)| a);
+    assert_eq!((verified.prefix_len(), verified.period()), (0, Some(12)));
+    assert_eq!(verified.nth(29), Some((1, 5)));
+    assert_eq!(verified.position_of(&(2, 4)), Some(10));
+
+    let shifted = iterate((1, 0), |&(a, b)| ((a + 1) % 4, (b + 1).min(6)))
+        .delooping_by_key_verified(|&(a, _)| a);
+    assert_eq!((shifted.prefix_len(), shifted.period()), (6, Some(4)));
+}
+
+#[test]
+fn constructors() {
+    let mut local = LoopingIter::new(iterate(0u8, |&x| (x + 1) % 3));
+    assert_eq!(local.nth(4), Some(1));
+    let sync = SyncLoopingIter::new_sync(iterate(0u8, |&x| (x + 1) % 3));
+    assert_eq!(sync.period(), Some(3));
+}
+
+#[test]
+fn brent_cycle() {
+    for start in 0..50u32 {
+        let step = |&x: &u32| (x * x + 7) % 97;
+        let (init, cycle) = iterate(start, step).delooping().loop_structure();
+        let mut brent = BrentCycle::new(start, step);
+        assert_eq!((brent.mu(), brent.lambda()), (init.len(), cycle.len()));
+        for n in [0, 1, 5, 20, 1000, 123456789] {
+            assert_eq!(
+                brent.nth(n),
+                iterate(start, step).delooping().nth(n).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn try_count() {
+    let mut iter = (0..5).delooping();
+    iter.next();
+    assert_eq!(iter.try_count(), Ok(4));
+    let looping = iterate(3u32, |&x| x * x % 100).delooping();
+    assert_eq!(looping.try_count(), Err(Looping { init: 2, cycle: 4 }));
+}
+
+#[test]
+fn sync_shared_between_threads() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let iter = iterate(3u32, |&x| x * x % 100).delooping_sync();
+    assert_send_sync(&iter);
+    std::thread::scope(|scope| {
+        for n in 0..8 {
+            let mut iter = iter.clone();
+            scope.spawn(move || assert_eq!(iter.nth(n + 2), Some([81, 61, 21, 41][n % 4])));
+        }
+    });
+    assert_eq!(iter.try_count(), Err(Looping { init: 2, cycle: 4 }));
+}
+
+#[test]
+fn queries() {
+    let iter = iterate(3u32, |&x| x * x % 100).delooping();
+    assert_eq!(iter.at(0), Some(3));
+    assert_eq!(iter.at(1_000_000_000_000_000_000_000), Some(21));
+    assert_eq!((iter.prefix_len(), iter.period()), (2, Some(4)));
+    assert_eq!(iter.position_of(&21), Some(4));
+    assert_eq!(iter.position_of(&22), None);
+
+    let set = iter.occurrences(|&x| x % 3 == 0);
+    assert_eq!(set, PeriodicSet::new(2, [0, 1], 4, [0, 2]));
+    assert!(set.contains(1) && set.contains(4) && set.contains(1002));
+    assert!(!set.contains(3) && !set.contains(1003));
+
+    let finite = (0..5).delooping();
+    assert_eq!((finite.prefix_len(), finite.period()), (5, None));
+    assert_eq!(finite.at(5), None);
+    assert!(!finite.occurrences(|_| true).contains(5));
+}
+
+#[test]
+fn queries_without_a_loop() {
+    // The search for the loop never ends, so queries must stop once they have their answer.
+    let mut iter = (0u64..).delooping();
+    assert_eq!(iter.nth(3), Some(3));
+    assert_eq!(iter.at(0), Some(0));
+    assert_eq!(iter.at(10), Some(10));
+    assert_eq!(iter.position_of(&7), Some(7));
+    assert_eq!(iter.next(), Some(4));
+
+    let shared = (0u64..).delooping_sync();
+    assert_eq!(shared.position_of(&20), Some(20));
+    assert_eq!(shared.at(2), Some(2));
+}
@@ -0,0 +1,182 @@
+use itertools::iproduct;
+
+use utils::{
+    bigint::BigInt,
+    numbers::{
+        bezout_identity, checked_bezout, checked_crt, checked_lcm, crt, isqrt, lcm, Mod, Ratio,
+    },
+};
+
+mod common;
+
+#[test]
+fn mod_arithmetic() {
+    for m in 1..20u64 {
+        for a in 0..m {
+            for b in 0..m {
+                let (x, y) = (Mod::new(a, m), Mod::new(b, m));
+                assert_eq!((x + y).value(), (a + b) % m);
+                assert_eq!((x - y).value(), (a + m - b) % m);
+                assert_eq!((x * y).value(), a * b % m);
+                assert_eq!((-x).value(), (m - a) % m);
+            }
+            let x = Mod::new(a, m);
+            assert_eq!(x.pow(5).value(), a.pow(5) % m);
+            match x.inverse() {
+                Some(inverse) => assert_eq!((x * inverse).value(), 1 % m),
+                None => assert!((1..m).all(|b| a * b % m != 1)),
+            }
+        }
+    }
+}
+
+#[test]
+fn mod_types() {
+    assert_eq!(Mod::new(-7i64, 5).value(), 3);
+    assert_eq!((Mod::new(-1i64, 7) * Mod::new(3, 7)).value(), 4);
+    assert_eq!(Mod::new(3i64, 7).inverse(), Some(Mod::new(5, 7)));
+
+    let m = u64::MAX as u128;
+    assert_eq!(Mod::new(m - 1, m) + Mod::new(m - 1, m), Mod::new(m - 2, m));
+    assert_eq!(Mod::new(2u128, m).pow(64).value(), 1);
+    assert_eq!(
+        Mod::new(u64::MAX - 1, u64::MAX) + Mod::new(5, u64::MAX),
+        Mod::new(4, u64::MAX)
+    );
+    assert_eq!(Mod::new(10u128, 17).to_string(), "10");
+}
+
+#[test]
+fn crt_matches_search() {
+    let moduli = [1u64, 2, 3, 4, 6, 9, 10, 12];
+    for &m1 in &moduli {
+        for &m2 in &moduli {
+            for &m3 in &moduli[..4] {
+                let l = lcm(lcm(m1, m2), m3);
+                for (r1, r2, r3) in iproduct!(0..m1, 0..m2, 0..m3) {
+                    let congruences = [(r1, m1), (r2, m2), (r3, m3)];
+                    let expected = (0..l)
+                        .find(|&n| congruences.iter().all(|&(r, m)| n % m == r))
+                        .map(|n| (n, l));
+                    assert_eq!(crt(&congruences), expected, "{:?}", congruences);
+                    assert_eq!(checked_crt(&congruences), expected, "{:?}", congruences);
+                }
+            }
+        }
+    }
+    assert_eq!(crt::<u64>(&[]), Some((0, 1)));
+}
+
+#[test]
+fn crt_signed_and_checked() {
+    assert_eq!(crt(&[(-1i64, 4), (-1, 6)]), Some((11, 12)));
+    assert_eq!(crt(&[(1i128, 4), (2, 6)]), None);
+
+    let p = (1u128 << 61) - 1;
+    assert_eq!(
+        checked_crt(&[(1u128, p), (2, 1 << 61)]),
+        crt(&[(1, p), (2, 1 << 61)])
+    );
+    let q = (1u128 << 64) + 13;
+    assert_eq!(checked_crt(&[(1u128, q), (2, q + 2)]), None);
+    let r = (1u128 << 127) - 1;
+    assert_eq!(
+        checked_crt(&[(1, 2), (r - 1, r)]),
+        Some((r - 1 + r, u128::MAX - 1))
+    );
+    assert_eq!(crt(&[(r - 1, r), (1, 2)]), Some((r - 1 + r, u128::MAX - 1)));
+    assert_eq!(
+        checked_crt(&[(5i128, 1 << 70), (5, 3 << 70)]),
+        Some((5, 3 << 70))
+    );
+}
+
+/// Pseudo-random numbers with a varying number of significant bits.
+fn samples(seed: u64, count: usize) -> impl Iterator<Item = u64> {
+    common::lcg(seed)
+        .take(count)
+        .map(|state| (state >> 11) >> (state % 53))
+}
+
+#[test]
+fn checked_matches_u128() {
+    for (a, b) in samples(1, 3000).zip(samples(2, 3000)) {
+        if a == 0 && b == 0 {
+            continue;
+        }
+        let expected = lcm(a as u128, b as u128);
+        assert_eq!(
+            checked_lcm(a, b),
+            u64::try_from(expected).ok(),
+            "lcm({}, {})",
+            a,
+            b
+        );
+
+        let wide = bezout_identity(a as u128, b as u128);
+        let narrow = checked_bezout(a, b).unwrap();
+        assert_eq!(
+            [narrow.k_a, narrow.k_b, narrow.f_a, narrow.f_b, narrow.gcd].map(u128::from),
+            [wide.k_a, wide.k_b, wide.f_a, wide.f_b, wide.gcd],
+        );
+        assert_eq!(narrow.sign, wide.sign);
+    }
+
+    for (m1, m2, r) in itertools::multizip((samples(3, 3000), samples(4, 3000), samples(5, 3000))) {
+        let (m1, m2) = (m1.max(1), m2.max(1));
+        let congruences = [(r % m1, m1), (r % m2, m2)];
+        let expected = crt(&congruences.map(|(r, m)| (r as u128, m as u128)));
+        match checked_crt(&congruences) {
+            Some((r, l)) => assert_eq!(Some((r as u128, l as u128)), expected),
+            None => assert!(
+                expected.unwrap().1 > u64::MAX as u128 || m1.max(m2) > u32::MAX as u64,
+                "{:?}",
+                congruences
+            ),
+        }
+    }
+}
+
+#[test]
+fn checked_edge_cases() {
+    assert!(checked_bezout(i64::MIN, -1).is_none());
+    assert_eq!(checked_lcm(0u32, 0), Some(0));
+    assert_eq!(checked_lcm(1u8 << 7, 3), None);
+    assert_eq!(checked_lcm(-4i32, 6), Some(-12));
+}
+
+#[test]
+fn isqrt_exact() {
+    for n in 0..10000u64 {
+        let s = isqrt(n);
+        assert!(s * s <= n && (s + 1) * (s + 1) > n);
+    }
+    assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    let square = (u64::MAX as u128 - 5).pow(2);
+    assert_eq!(isqrt(square), u64::MAX as u128 - 5);
+    assert_eq!(isqrt(square - 1), u64::MAX as u128 - 6);
+}
+
+#[test]
+fn ratio() {
+    let r = |n: i64, d: i64| Ratio::new(n, d);
+    assert_eq!(r(6, -4), r(-3, 2));
+    assert_eq!((r(-3, 2).numer(), r(-3, 2).denom()), (&-3, &2));
+    assert_eq!(r(1, 2) + r(1, 3), r(5, 6));
+    assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+    assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+    assert_eq!(r(2, 3) / r(-4, 9), r(-3, 2));
+    assert_eq!(-r(2, 3), r(-2, 3));
+    assert!(r(-1, 2) < r(1, 3) && r(2, 3) > r(3, 5));
+    assert_eq!([r(7, 2).floor(), r(7, 2).ceil()], [3, 4]);
+    assert_eq!([r(-7, 2).floor(), r(-7, 2).ceil()], [-4, -3]);
+    assert_eq!([r(-4, 2).floor(), r(-4, 2).ceil()], [-2, -2]);
+    assert_eq!(Ratio::from(5).to_string(), "5");
+    assert_eq!(r(-3, 6).to_string(), "-1/2");
+
+    assert_eq!(Ratio::new(3u64, 4) - Ratio::new(1, 4), Ratio::new(1, 2));
+    assert_eq!(Ratio::new(5u64, 4).ceil(), 2);
+    let big = Ratio::new(BigInt::from(10), BigInt::from(-4));
+    assert_eq!(big.floor(), BigInt::from(-3));
+}
@@ -0,0 +1,122 @@
+use std::fs;
+
+use utils::{days, parse::ParseError, solution::SolveError};
+
+fn error(day: usize, input: &str) -> ParseError {
+    match days::get(day).unwrap().parse(input) {
+        Ok(_) => panic!("day {} accepted {:?}", day, input),
+        Err(err) => err,
+    }
+}
+
+fn check(day: usize, input: &str, line: usize, column: usize, snippet: &str) {
+    let err = error(day, input);
+    assert_eq!(
+        (err.line(), err.column(), err.snippet()),
+        (line, column, snippet),
+        "day {}: {}",
+        day,
+        err
+    );
+}
+
+#[test]
+fn line_without_digit() {
+    check(1, "1abc2\nabc\n", 2, 1, "abc");
+}
+
+#[test]
+fn missing_start() {
+    check(10, ".F7\n.LJ", 2, 4, ".LJ");
+    check(21, "...\n.#.", 2, 4, ".#.");
+}
+
+#[test]
+fn unconnected_start() {
+    check(10, "...\n.S|\n...", 2, 2, ".S|");
+}
+
+#[test]
+fn empty_grid() {
+    check(16, "", 1, 1, "");
+    check(16, "\n\n", 3, 1, "");
+}
+
+#[test]
+fn bad_direction_digit() {
+    check(18, "R 6 (#70c710)\nD 5 (#0dc574)", 2, 12, "D 5 (#0dc574)");
+}
+
+#[test]
+fn missing_broadcaster() {
+    check(20, "%a -> b\n&b -> a", 2, 8, "&b -> a");
+}
+
+#[test]
+fn trail_ends() {
+    check(23, "#.#\n...\n#..", 3, 1, "#..");
+    check(23, "###\n...\n#.#", 1, 1, "###");
+    check(23, "#.#", 1, 4, "#.#");
+}
+
+#[test]
+fn network_nodes() {
+    check(8, "\n\nAAA = (AAA, AAA)", 1, 1, "");
+    check(
+        8,
+        "LR\n\nAAA = (BBB, AAA)\nBBB = (CCC, ZZZ)",
+        4,
+        8,
+        "BBB = (CCC, ZZZ)",
+    );
+}
+
+fn example(name: &str) -> String {
+    fs::read_to_string(format!(
+        "{}/tests/examples/inputs/{}.txt",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    ))
+    .unwrap()
+}
+
+#[test]
+fn unanswerable_parts() {
+    let solution = days::get(1).unwrap();
+    let input = solution.parse("1abc2\ntwone\n").unwrap();
+    assert_eq!(
+        solution.part1(&*input),
+        Err(SolveError::new("a line has no digit"))
+    );
+    assert_eq!(solution.part2(&*input), Ok(String::from("33")));
+
+    let solution = days::get(20).unwrap();
+    let input = solution
+        .parse("broadcaster -> a, b, c\n%a -> b\n%b -> c\n%c -> inv\n&inv -> a")
+        .unwrap();
+    assert_eq!(solution.part1(&*input), Ok(String::from("32000000")));
+    assert_eq!(
+        solution.part2(&*input),
+        Err(SolveError::new("no counters feeding `rx`"))
+    );
+
+    let solution = days::get(8).unwrap();
+    let input = solution.parse(&example("8b")).unwrap();
+    assert_eq!(
+        solution.part1(&*input),
+        Err(SolveError::new("no node `AAA`"))
+    );
+    assert_eq!(solution.part2(&*input), Ok(String::from("6")));
+    let input = solution
+        .parse("L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)")
+        .unwrap();
+    assert_eq!(
+        solution.part1(&*input),
+        Err(SolveError::new("`ZZZ` cannot be reached from `AAA`"))
+    );
+
+    let solution = days::get(21).unwrap();
+    let input = solution.parse(&example("21")).unwrap();
+    assert_eq!(solution.part1(&*input), Ok(String::from("42")));
+    assert!(solution.part2(&*input).is_err());
+}
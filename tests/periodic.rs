@@ -0,0 +1,64 @@
+use itertools::Itertools;
+
+use utils::periodic::PeriodicSet;
+
+/// A few sets with various prefixes and periods, including finite ones.
+fn sets() -> Vec<PeriodicSet> {
+    vec![
+        PeriodicSet::empty(),
+        PeriodicSet::all(),
+        PeriodicSet::new(3, [1], 1, []),
+        PeriodicSet::new(0, [], 4, [1, 3]),
+        PeriodicSet::new(2, [0], 6, [5]),
+        PeriodicSet::new(5, [0, 2, 4], 9, [0, 3, 7]),
+        PeriodicSet::new(7, [6], 10, [1, 6]),
+        PeriodicSet::new(1, [], 15, [2, 4, 11]),
+    ]
+}
+
+const LIMIT: u128 = 200;
+
+#[test]
+fn contains_and_iter_agree() {
+    for set in sets() {
+        let expected = (0..LIMIT).filter(|&n| set.contains(n)).collect_vec();
+        let actual = set.iter().take_while(|&n| n < LIMIT).collect_vec();
+        assert_eq!(actual, expected, "{:?}", set);
+        assert_eq!(set.min(), expected.first().copied(), "{:?}", set);
+    }
+}
+
+#[test]
+fn intersect_and_union() {
+    for (a, b) in sets().iter().cartesian_product(&sets()) {
+        let intersection = a.intersect(b).unwrap();
+        let union = a.union(b).unwrap();
+        for n in 0..LIMIT {
+            assert_eq!(intersection.contains(n), a.contains(n) && b.contains(n));
+            assert_eq!(union.contains(n), a.contains(n) || b.contains(n));
+        }
+    }
+}
+
+#[test]
+fn large_periods() {
+    let a = PeriodicSet::new(0, [], 1 << 64, [6]);
+    let b = PeriodicSet::new(0, [], 3, [1]);
+    let intersection = a.intersect(&b).unwrap();
+    assert_eq!(intersection.period(), 3 << 64);
+    assert_eq!(intersection.min(), Some((1 << 64) + 6));
+
+    // The period of the result does not fit.
+    let c = PeriodicSet::new(0, [], (1 << 100) - 1, [0]);
+    assert_eq!(a.intersect(&c), None);
+    assert_eq!(a.union(&c), None);
+    // The period fits, even though the residues multiply past it while solving.
+    let d = PeriodicSet::new(0, [], 2, [1]);
+    let e = PeriodicSet::new(0, [], (1 << 127) - 1, [5]);
+    let intersection = d.intersect(&e).unwrap();
+    assert_eq!(intersection.period(), u128::MAX - 1);
+    assert_eq!(intersection.min(), Some(5));
+    let f = PeriodicSet::new(0, [], (1 << 127) - 1, [6]);
+    let intersection = d.intersect(&f).unwrap();
+    assert_eq!(intersection.min(), Some((1 << 127) + 5));
+}
@@ -0,0 +1,57 @@
+use utils::{
+    numbers::Ratio,
+    poly::{degree, differences, extrapolate, interpolate, lagrange},
+};
+
+/// `3x^3 - 2x^2 + 7`
+fn cubic(x: i64) -> i64 {
+    3 * x * x * x - 2 * x * x + 7
+}
+
+#[test]
+fn extrapolate_any_index() {
+    let seq = (0..6).map(cubic).collect::<Vec<_>>();
+    for k in -50..100 {
+        assert_eq!(extrapolate(&seq, k), cubic(k), "k = {}", k);
+    }
+    assert_eq!(extrapolate(&[0, 3, 6, 9, 12, 15], 6), 18);
+    assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], -1), 5);
+    assert_eq!(extrapolate(&[5i64], 10), 5);
+    assert_eq!(extrapolate::<i64>(&[], 3), 0);
+}
+
+#[test]
+fn degrees() {
+    assert_eq!(differences(&[1, 3, 6, 10, 15, 21]), [1, 2, 1]);
+    assert_eq!(degree(&(0..6).map(cubic).collect::<Vec<_>>()), Some(3));
+    assert_eq!(degree(&(0..4).map(cubic).collect::<Vec<_>>()), None);
+    assert_eq!(degree(&[4, 4, 4]), Some(0));
+    assert_eq!(degree(&[0, 0]), Some(0));
+    assert_eq!(degree(&[1, 2, 4, 8, 16, 32]), None);
+}
+
+#[test]
+fn lagrange_and_interpolate() {
+    let points = [
+        (-2i64, cubic(-2)),
+        (1, cubic(1)),
+        (4, cubic(4)),
+        (7, cubic(7)),
+    ];
+    for x in -10..10 {
+        assert_eq!(lagrange(&points, x), Ratio::from(cubic(x)));
+    }
+    assert_eq!(
+        interpolate(&points),
+        [7, 0, -2, 3].map(Ratio::from).to_vec()
+    );
+
+    // y = x^2 / 2 through integer points has fractional coefficients.
+    let points = [(0i64, 0), (2, 2), (4, 8)];
+    assert_eq!(
+        interpolate(&points),
+        [Ratio::from(0), Ratio::from(0), Ratio::new(1, 2)]
+    );
+    assert_eq!(lagrange(&points, 3), Ratio::new(9, 2));
+    assert_eq!(interpolate(&[(1i64, 5), (3, 5)]), [Ratio::from(5)]);
+}
@@ -0,0 +1,129 @@
+use strum::IntoEnumIterator;
+use utils::{direction::Direction4, rect::Rect};
+
+/// A 3x3 rect with each cell next to the center labelled by the direction it lies in.
+fn compass() -> Rect<Option<Direction4>> {
+    let mut rect: Rect<Option<Direction4>> = (0..3).map(|_| vec![None; 3]).collect();
+    for dir in Direction4::iter() {
+        rect[dir.advance((1, 1))] = Some(dir);
+    }
+    rect
+}
+
+fn check_transform(
+    transform: fn(&mut Rect<Option<Direction4>>),
+    dir_map: fn(Direction4) -> Direction4,
+) {
+    let mut rect = compass();
+    transform(&mut rect);
+    for dir in Direction4::iter() {
+        assert_eq!(rect[dir_map(dir).advance((1, 1))], Some(dir));
+    }
+}
+
+#[test]
+fn transforms_match_directions() {
+    check_transform(Rect::rotate_cw, Direction4::rotate_cw);
+    check_transform(Rect::rotate_ccw, Direction4::rotate_ccw);
+    check_transform(Rect::rotate_180, Direction4::opposite);
+    check_transform(Rect::flip_h, Direction4::reflect_h);
+    check_transform(Rect::flip_v, Direction4::reflect_v);
+    check_transform(Rect::transpose, Direction4::reflect_nwse);
+}
+
+#[test]
+fn transform_non_square() {
+    let mut rect = Rect::try_from("abc\ndef").unwrap();
+    rect.rotate_cw();
+    assert_eq!(rect.to_string(), "da\neb\nfc\n");
+    rect.rotate_ccw();
+    assert_eq!(rect.to_string(), "abc\ndef\n");
+    rect.transpose();
+    assert_eq!(rect.to_string(), "ad\nbe\ncf\n");
+}
+
+#[test]
+fn rows_and_cols() {
+    let mut rect = Rect::try_from("abc\ndef").unwrap();
+    assert_eq!(rect.as_slice(), ['a', 'b', 'c', 'd', 'e', 'f']);
+    assert_eq!(rect.row(1), ['d', 'e', 'f']);
+    assert_eq!(rect.col(1).collect::<String>(), "be");
+    rect.push_col(vec!['x', 'y']);
+    assert_eq!(rect.to_string(), "abcx\ndefy\n");
+    assert_eq!(rect.pop_col(), Some(vec!['x', 'y']));
+    assert_eq!(rect.pop_row(), Some(vec!['d', 'e', 'f']));
+    assert_eq!(rect.iter().rev().collect::<Vec<_>>(), [['a', 'b', 'c']]);
+    assert_eq!(rect.into_iter().collect::<Vec<_>>(), [vec!['a', 'b', 'c']]);
+}
+
+#[test]
+fn neighbors() {
+    let mut rect = Rect::try_from("abc\ndef").unwrap();
+    let near = |rect: &Rect<char>, pos: (usize, usize)| {
+        rect.neighbors4(pos)
+            .map(|(dir, pos, &c)| (dir, pos, c))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        near(&rect, (0, 0)),
+        [
+            (Direction4::East, (1, 0), 'b'),
+            (Direction4::South, (0, 1), 'd')
+        ]
+    );
+    assert_eq!(rect.neighbors8((1, 0)).count(), 5);
+    for (_, _, c) in rect.neighbors4_mut((1, 1)) {
+        *c = c.to_ascii_uppercase();
+    }
+    assert_eq!(rect.to_string(), "aBc\nDeF\n");
+    let wrapped = rect
+        .neighbors4_wrapping((0, 0))
+        .map(|(_, pos, &c)| (pos, c))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        wrapped,
+        [((1, 0), 'B'), ((0, 1), 'D'), ((-1, 0), 'c'), ((0, -1), 'D')]
+    );
+}
+
+#[test]
+fn parse() {
+    let rect = Rect::try_from("ab\ncd\n").unwrap();
+    assert_eq!((rect.width(), rect.height()), (2, 2));
+    assert_eq!(rect[(1, 1)], 'd');
+    let empty = Rect::try_from("").unwrap();
+    assert_eq!((empty.width(), empty.height()), (0, 0));
+
+    let err = Rect::try_from("abc\ndef\ngh\n").unwrap_err();
+    assert_eq!((err.line(), err.column(), err.snippet()), (3, 1, "gh"));
+    assert_eq!(err.message(), "row has width 2, expected 3");
+
+    let input = "grid:\n.#\n#x\n";
+    let err = Rect::parse_grid_in(input, &input[6..], |c| match c {
+        '.' => Ok(false),
+        '#' => Ok(true),
+        _ => Err("expected `.` or `#`"),
+    })
+    .unwrap_err();
+    assert_eq!((err.line(), err.column(), err.snippet()), (3, 2, "#x"));
+    assert_eq!(
+        err.message(),
+        "unexpected character `x`: expected `.` or `#`"
+    );
+}
+
+#[test]
+fn render() {
+    let rect: Rect<u8> = [vec![1, 0, 3], vec![0, 5, 0]].into_iter().collect();
+    let digit = |&n: &u8| char::from_digit(n as u32, 10).unwrap();
+    assert_eq!(rect.render(digit), "103\n050\n");
+    assert_eq!(
+        rect.render_overlay(digit, [(0isize, 0isize), (2, 1), (-1, 0), (3, 1)], '#'),
+        "#03\n05#\n"
+    );
+    assert_eq!(format!("{:?}", rect), "Rect 3x2\n1 0 3\n0 5 0\n");
+    assert_eq!(
+        format!("{:?}", Rect::try_from("ab").unwrap()),
+        "Rect 2x1\n'a' 'b'\n"
+    );
+}
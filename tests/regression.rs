@@ -0,0 +1,123 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use utils::{days, solution::DynSolution};
+
+/// Checks `solution` on `input` against `answers`, which holds the expected answer to each part
+/// on its own line. A part whose line is missing or `-` is not checked.
+fn check(solution: &dyn DynSolution, name: &str, input: &Path, answers: &Path) {
+    let input = fs::read_to_string(input).unwrap();
+    let answers = fs::read_to_string(answers).unwrap();
+    let mut expected = answers.lines().map(str::trim);
+    let parsed = solution
+        .parse(&input)
+        .unwrap_or_else(|err| panic!("{}: {}", name, err));
+    for part in [1, 2] {
+        match expected.next() {
+            None | Some("-") => (),
+            Some(answer) => {
+                let actual = match part {
+                    1 => solution.part1(&*parsed),
+                    _ => solution.part2(&*parsed),
+                }
+                .unwrap_or_else(|err| panic!("{} part {}: {}", name, part, err));
+                assert_eq!(actual, answer, "{} part {}", name, part);
+            }
+        }
+    }
+}
+
+/// Examples for `day` are named `<day>.txt` or `<day><suffix>.txt` with a non-numeric suffix.
+fn examples(day: usize) -> Vec<(String, PathBuf)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/examples/inputs");
+    let prefix = day.to_string();
+    let mut examples: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_owned();
+            let suffix = name.strip_prefix(&prefix)?;
+            (path.extension()? == "txt" && !suffix.starts_with(|c: char| c.is_ascii_digit()))
+                .then_some((name, path))
+        })
+        .collect();
+    examples.sort();
+    examples
+}
+
+fn run_examples(day: usize) {
+    let solution = days::get(day).unwrap();
+    let examples = examples(day);
+    assert!(!examples.is_empty(), "no examples for day {}", day);
+    let answers = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/examples/answers");
+    for (name, input) in examples {
+        check(
+            solution,
+            &format!("example {}", name),
+            &input,
+            &answers.join(format!("{}.txt", name)),
+        );
+    }
+}
+
+fn run_input(day: usize) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let input = root.join(format!("inputs/{}.txt", day));
+    let answers = root.join(format!("answers/{}.txt", day));
+    if !input.exists() || !answers.exists() {
+        eprintln!("day {}: no input or answers, skipping", day);
+        return;
+    }
+    check(
+        days::get(day).unwrap(),
+        &format!("day {}", day),
+        &input,
+        &answers,
+    );
+}
+
+macro_rules! days {
+    ($($day:literal => $examples:ident, $input:ident;)*) => {
+        $(
+            #[test]
+            fn $examples() {
+                run_examples($day)
+            }
+
+            #[test]
+            fn $input() {
+                run_input($day)
+            }
+        )*
+    };
+}
+
+days! {
+    1 => day1_examples, day1_input;
+    2 => day2_examples, day2_input;
+    3 => day3_examples, day3_input;
+    4 => day4_examples, day4_input;
+    5 => day5_examples, day5_input;
+    6 => day6_examples, day6_input;
+    7 => day7_examples, day7_input;
+    8 => day8_examples, day8_input;
+    9 => day9_examples, day9_input;
+    10 => day10_examples, day10_input;
+    11 => day11_examples, day11_input;
+    12 => day12_examples, day12_input;
+    13 => day13_examples, day13_input;
+    14 => day14_examples, day14_input;
+    15 => day15_examples, day15_input;
+    16 => day16_examples, day16_input;
+    17 => day17_examples, day17_input;
+    18 => day18_examples, day18_input;
+    19 => day19_examples, day19_input;
+    20 => day20_examples, day20_input;
+    21 => day21_examples, day21_input;
+    22 => day22_examples, day22_input;
+    23 => day23_examples, day23_input;
+    24 => day24_examples, day24_input;
+    25 => day25_examples, day25_input;
+}
@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use utils::{
+    rect::Rect,
+    search::{astar, astar_with, bfs, bfs_with, dijkstra, dijkstra_with, grid_bfs, grid_dijkstra},
+};
+
+const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.";
+
+#[test]
+fn grid_searches() {
+    let maze = Rect::try_from(MAZE).unwrap();
+    let path = grid_bfs(&maze, (0, 0), (6, 0), |&c| c == '.').unwrap();
+    assert_eq!(path.cost, 12);
+    assert_eq!(path.path.len(), 13);
+    assert_eq!(path.path.first(), Some(&(0, 0)));
+    assert_eq!(path.path.last(), Some(&(6, 0)));
+    assert!(grid_bfs(&maze, (0, 0), (2, 0), |&c| c == '.').is_none());
+
+    let weighted = grid_dijkstra(&maze, (0, 0), (6, 0), |&c| match c {
+        '.' => Some(1u32),
+        _ => Some(5),
+    })
+    .unwrap();
+    assert_eq!(weighted.cost, 10);
+}
+
+#[test]
+fn searches_agree() {
+    let successors = |&n: &u32| [(n + 1, 1u32), (n * 2, 1), (n * 3, 2)];
+    let by_dijkstra = dijkstra(1, successors, |&n| n == 100).unwrap();
+    let by_astar = astar(1, successors, |_| 0, |&n| n == 100).unwrap();
+    assert_eq!(by_dijkstra.cost, by_astar.cost);
+    assert_eq!(by_dijkstra.path.first(), Some(&1));
+    assert_eq!(by_dijkstra.path.last(), Some(&100));
+    let by_bfs = bfs(1, |&n| [n + 1, n * 2], |&n| n == 100).unwrap();
+    assert_eq!(by_bfs.cost, 8);
+}
+
+/// Open cells of `maze` orthogonally next to `pos`.
+fn open_neighbors(maze: &Rect<char>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+    maze.neighbors4(pos)
+        .filter(|&(_, _, &c)| c == '.')
+        .map(|(_, next, _)| next)
+        .collect()
+}
+
+fn moves(maze: &Rect<char>, pos: (usize, usize)) -> Vec<((usize, usize), u32)> {
+    open_neighbors(maze, pos)
+        .into_iter()
+        .map(|next| (next, 1))
+        .collect()
+}
+
+#[test]
+fn astar_manhattan() {
+    let maze = Rect::try_from(MAZE).unwrap();
+    let manhattan = |&(x, y): &(usize, usize)| (x.abs_diff(6) + y) as u32;
+    let by_astar = astar(
+        (0, 0),
+        |&pos| moves(&maze, pos),
+        manhattan,
+        |&pos| pos == (6, 0),
+    )
+    .unwrap();
+    assert_eq!(by_astar.cost, 12);
+    assert_eq!(
+        by_astar.path,
+        [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (2, 3),
+            (3, 3),
+            (4, 3),
+            (4, 2),
+            (5, 2),
+            (6, 2),
+            (6, 1),
+            (6, 0)
+        ]
+    );
+    let by_dijkstra = dijkstra((0, 0), |&pos| moves(&maze, pos), |&pos| pos == (6, 0)).unwrap();
+    assert_eq!(by_dijkstra.path, by_astar.path);
+    assert!(by_astar.visited < by_dijkstra.visited);
+}
+
+#[test]
+fn searches_with_custom_seen_set() {
+    let maze = Rect::try_from(MAZE).unwrap();
+    let goal = |&pos: &(usize, usize)| pos == (6, 0);
+
+    // Every expanded state is inserted once, and the search stops at the goal.
+    let mut seen = HashSet::new();
+    let result = dijkstra_with(
+        (0, 0),
+        |&pos| moves(&maze, pos),
+        goal,
+        |&pos| seen.insert(pos),
+    )
+    .unwrap();
+    assert_eq!(result.cost, 12);
+    assert_eq!(seen.len(), result.visited);
+    assert!(seen.len() < maze.cells().filter(|&(_, _, &c)| c == '.').count());
+
+    // Refusing a cell on the only route cuts the goal off.
+    let blocked = || {
+        let mut seen = HashSet::new();
+        move |&pos: &(usize, usize)| pos != (4, 2) && seen.insert(pos)
+    };
+    assert!(astar_with((0, 0), |&pos| moves(&maze, pos), |_| 0, goal, blocked()).is_none());
+    assert!(bfs_with((0, 0), |&pos| open_neighbors(&maze, pos), goal, blocked()).is_none());
+}
@@ -0,0 +1,42 @@
+use utils::{
+    direction::Direction4,
+    state_set::{GridCellSet, GridStateSet},
+};
+
+#[test]
+fn insert_reports_new_states() {
+    let mut set = GridStateSet::<3>::new(5, 4);
+    assert!(set.is_empty());
+    assert!(set.insert((4, 3), Direction4::North, 2));
+    assert!(!set.insert((4, 3), Direction4::North, 2));
+    assert!(set.insert((4, 3), Direction4::North, 1));
+    assert!(set.insert((0, 0), Direction4::East, 0));
+    assert!(set.contains((4, 3), Direction4::North, 1));
+    assert!(!set.contains((4, 3), Direction4::South, 1));
+    assert!(!set.contains((-1, 3), Direction4::North, 1));
+    assert_eq!(set.len(), 3);
+    assert_eq!(set.positions().collect::<Vec<_>>(), [(0, 0), (4, 3)]);
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn cell_sets() {
+    let mut a = GridCellSet::new(9, 8);
+    assert!(a.insert((8, 7)));
+    assert!(!a.insert((8, 7)));
+    assert!(a.insert((0, 1)));
+    assert!(!a.contains((-1, 1)));
+    assert!(!a.contains((9, 0)));
+    let mut b = GridCellSet::new(9, 8);
+    b.insert((0, 1));
+    b.insert((3, 2));
+    assert_ne!(a, b);
+    a.union_with(&b);
+    assert_eq!(a.len(), 3);
+    assert_eq!(a.positions().collect::<Vec<_>>(), [(0, 1), (3, 2), (8, 7)]);
+    b.insert((8, 7));
+    assert_eq!(a, b);
+    a.clear();
+    assert!(a.is_empty());
+}